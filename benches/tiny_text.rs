@@ -0,0 +1,45 @@
+//! Entity count and rebuild time of TinyText strings built as one entity per
+//! cube versus one merged mesh per string.
+//! Run with: cargo bench --bench tiny_text
+
+use bevy::prelude::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+use pi_vs_pi::tiny_text::{TextMeshMode, TinyText3d, TinyText3dBundle, TinyTextPlugin};
+
+/// A 20-character label, about the length of a long category name
+const LABELS: [&str; 2] = ["KOBOLDS vs TROGLODYT", "Strength: 12.5 (+3%)"];
+
+fn text_app(mode: TextMeshMode) -> (App, Entity) {
+    let mut app = App::new();
+    app.init_resource::<Assets<Mesh>>()
+        .init_resource::<Assets<StandardMaterial>>()
+        .add_plugins(TinyTextPlugin);
+    let text = app
+        .world
+        .spawn(TinyText3dBundle::new(
+            TinyText3d::new(LABELS[0], Color::WHITE).with_mode(mode),
+            Transform::default(),
+        ))
+        .id();
+    app.update();
+    (app, text)
+}
+
+fn rebuild(c: &mut Criterion) {
+    for (name, mode) in [("cubes", TextMeshMode::Cubes), ("merged", TextMeshMode::Merged)] {
+        let (mut app, text) = text_app(mode);
+        println!("{}: {} entities for {:?}", name, app.world.entities().len(), LABELS[0]);
+
+        let mut frame = 0;
+        c.bench_function(&format!("rebuild_20_chars_{}", name), |b| {
+            b.iter(|| {
+                frame += 1;
+                app.world.get_mut::<TinyText3d>(text).unwrap().text = LABELS[frame % 2].to_string();
+                app.update();
+            })
+        });
+    }
+}
+
+criterion_group!(benches, rebuild);
+criterion_main!(benches);
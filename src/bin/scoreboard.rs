@@ -5,7 +5,7 @@ use bevy::prelude::*;
 use bevy::window::WindowResolution;
 use bevy::math::primitives::{Cuboid, Plane3d};
 use std::f32::consts::PI;
-use pi_vs_pi::tiny_text::TinyFont;
+use pi_vs_pi::tiny_text::{TextShadow, TextStyle, TinyText3d, TinyText3dBundle, TinyTextPlugin, VoxelShape};
 
 fn main() {
     App::new()
@@ -17,9 +17,10 @@ fn main() {
             }),
             ..default()
         }))
+        .add_plugins(TinyTextPlugin)
         .insert_resource(CharacterCycler::new())
-        .add_systems(Startup, setup_scene)
-        .add_systems(Update, (update_character_display, cleanup_old_text))
+        .add_systems(Startup, (setup_scene, spawn_labels))
+        .add_systems(Update, update_character_display)
         .run();
 }
 
@@ -27,7 +28,6 @@ fn main() {
 struct CharacterCycler {
     timer: Timer,
     current_char: u8,
-    text_entities: Vec<Entity>,
 }
 
 impl CharacterCycler {
@@ -35,7 +35,6 @@ impl CharacterCycler {
         Self {
             timer: Timer::from_seconds(0.1, TimerMode::Repeating),
             current_char: 32, // Start with space character
-            text_entities: Vec::new(),
         }
     }
     fn get_current_char(&self) -> char {
@@ -57,8 +56,15 @@ impl CharacterCycler {
     }
 }
 
-#[derive(Component)]
-struct TextCube;
+/// Which line of the demo a text entity shows
+#[derive(Component, Clone, Copy)]
+enum DemoLabel {
+    Current,
+    Info,
+    Preview,
+    Spinning,
+    Progress,
+}
 
 fn setup_scene(
     mut commands: Commands,
@@ -137,46 +143,45 @@ fn setup_scene(
     }
 }
 
+fn spawn_labels(mut commands: Commands) {
+    let labels = [
+        (DemoLabel::Current, Vec3::new(-2.0, 5.0, 0.0), 1.0, 4.0, Color::rgb(1.0, 0.6, 0.2)),
+        (DemoLabel::Info, Vec3::new(-8.0, 1.0, 0.0), 0.3, 1.2, Color::rgb(0.4, 0.8, 1.0)),
+        (DemoLabel::Preview, Vec3::new(-6.0, -1.0, 0.0), 0.25, 1.0, Color::rgb(0.6, 0.6, 0.6)),
+        (DemoLabel::Spinning, Vec3::new(8.0, 3.0, 0.0), 0.7, 3.0, Color::rgb(0.8, 0.2, 0.8)),
+        (DemoLabel::Progress, Vec3::new(-8.0, -3.0, 0.0), 0.3, 1.2, Color::rgb(0.2, 1.0, 0.4)),
+    ];
+    for (label, origin, scale, spacing, color) in labels {
+        // The large characters show off the extruded and dot-matrix styles
+        let style = match label {
+            DemoLabel::Current => TextStyle::default()
+                .with_depth(3)
+                .with_voxel(VoxelShape::Beveled)
+                .with_shadow(TextShadow::drop(Color::rgb(0.15, 0.08, 0.02))),
+            DemoLabel::Spinning => TextStyle::default().with_depth(2).with_voxel(VoxelShape::Sphere),
+            _ => TextStyle::default(),
+        };
+        commands.spawn((
+            TinyText3dBundle::new(
+                TinyText3d::new("", color)
+                    .with_scale(Vec3::splat(scale))
+                    .with_spacing(Vec3::new(spacing, 0.0, 0.0))
+                    .with_style(style),
+                Transform::from_translation(origin),
+            ),
+            label,
+        ));
+    }
+}
+
 fn update_character_display(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
     mut cycler: ResMut<CharacterCycler>,
+    mut labels: Query<(&DemoLabel, &mut TinyText3d, &mut Transform)>,
     time: Res<Time>,
 ) {
     cycler.timer.tick(time.delta());
     if cycler.timer.just_finished() {
-        for entity in cycler.text_entities.drain(..) {
-            if let Some(mut entity_commands) = commands.get_entity(entity) {
-                entity_commands.despawn();
-            }
-        }
         let current_char = cycler.get_current_char();
-        let display_info = cycler.get_display_string();
-        spawn_text_with_tracking(
-            &mut commands,
-            &mut meshes,
-            &mut materials,
-            &mut cycler.text_entities,
-            &current_char.to_string(),
-            Vec3::new(-2.0, 5.0, 0.0),
-            Vec3::new(1.0, 1.0, 1.0),
-            Quat::IDENTITY,
-            Vec3::new(4.0, 0.0, 0.0),
-            Color::rgb(1.0, 0.6, 0.2),
-        );
-        spawn_text_with_tracking(
-            &mut commands,
-            &mut meshes,
-            &mut materials,
-            &mut cycler.text_entities,
-            &display_info,
-            Vec3::new(-8.0, 1.0, 0.0),
-            Vec3::new(0.3, 0.3, 0.3),
-            Quat::IDENTITY,
-            Vec3::new(1.2, 0.0, 0.0),
-            Color::rgb(0.4, 0.8, 1.0),
-        );
         let preview_text = (1..=5)
             .map(|offset| {
                 let next_ascii = if cycler.current_char + offset > 126 {
@@ -188,112 +193,18 @@ fn update_character_display(
                 if next_char.is_whitespace() { '·' } else { next_char }
             })
             .collect::<String>();
-        spawn_text_with_tracking(
-            &mut commands,
-            &mut meshes,
-            &mut materials,
-            &mut cycler.text_entities,
-            &format!("Next: {}", preview_text),
-            Vec3::new(-6.0, -1.0, 0.0),
-            Vec3::new(0.25, 0.25, 0.25),
-            Quat::IDENTITY,
-            Vec3::new(1.0, 0.0, 0.0),
-            Color::rgb(0.6, 0.6, 0.6),
-        );
-        let rotation = Quat::from_rotation_y(time.elapsed_seconds() * 0.5);
-        spawn_text_with_tracking(
-            &mut commands,
-            &mut meshes,
-            &mut materials,
-            &mut cycler.text_entities,
-            &current_char.to_string(),
-            Vec3::new(8.0, 3.0, 0.0),
-            Vec3::new(0.7, 0.7, 0.7),
-            rotation,
-            Vec3::new(3.0, 0.0, 0.0),
-            Color::rgb(0.8, 0.2, 0.8),
-        );
         let progress = (cycler.current_char - 32) as f32 / 94.0;
-        let progress_text = format!("Progress: {:.0}%", progress * 100.0);
-        spawn_text_with_tracking(
-            &mut commands,
-            &mut meshes,
-            &mut materials,
-            &mut cycler.text_entities,
-            &progress_text,
-            Vec3::new(-8.0, -3.0, 0.0),
-            Vec3::new(0.3, 0.3, 0.3),
-            Quat::IDENTITY,
-            Vec3::new(1.2, 0.0, 0.0),
-            Color::rgb(0.2, 1.0, 0.4),
-        );
-        cycler.advance();
-    }
-}
-
-fn spawn_text_with_tracking(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-    entity_tracker: &mut Vec<Entity>,
-    text: &str,
-    origin: Vec3,
-    scale: Vec3,
-    orientation: Quat,
-    offset: Vec3,
-    color: Color,
-) {
-    let font = TinyFont::new();
-    let mut cursor = origin;
-    let cube_mesh = meshes.add(Mesh::from(Cuboid { half_size: Vec3::splat(0.5) }));
-    let cube_material = materials.add(StandardMaterial {
-        base_color: color,
-        metallic: 0.2,
-        perceptual_roughness: 0.3,
-        ..default()
-    });
-    for c in text.chars() {
-        if let Some(glyph) = font.get_glyph(c) {
-            for cube_pos in glyph {
-                let local_pos = Vec3::new(
-                    cube_pos.x as f32 * scale.x,
-                    cube_pos.y as f32 * scale.y,
-                    0.0
-                );
-                let world_pos = cursor + orientation * local_pos;
-                let entity = commands.spawn((
-                    PbrBundle {
-                        mesh: cube_mesh.clone(),
-                        material: cube_material.clone(),
-                        transform: Transform {
-                            translation: world_pos,
-                            rotation: orientation,
-                            scale: scale * 0.8,
-                        },
-                        ..default()
-                    },
-                    TextCube,
-                )).id();
-                entity_tracker.push(entity);
-            }
-        }
-        cursor += orientation * offset;
-    }
-}
-
-fn cleanup_old_text(
-    mut commands: Commands,
-    query: Query<Entity, With<TextCube>>,
-    cycler: Res<CharacterCycler>,
-) {
-    if cycler.timer.just_finished() {
-        for entity in query.iter() {
-            if !cycler.text_entities.contains(&entity) {
-                if let Some(mut entity_commands) = commands.get_entity(entity) {
-                    entity_commands.despawn();
-                }
+        for (label, mut text, mut transform) in labels.iter_mut() {
+            text.text = match label {
+                DemoLabel::Current | DemoLabel::Spinning => current_char.to_string(),
+                DemoLabel::Info => cycler.get_display_string(),
+                DemoLabel::Preview => format!("Next: {}", preview_text),
+                DemoLabel::Progress => format!("Progress: {:.0}%", progress * 100.0),
+            };
+            if let DemoLabel::Spinning = label {
+                transform.rotation = Quat::from_rotation_y(time.elapsed_seconds() * 0.5);
             }
         }
+        cycler.advance();
     }
 }
-
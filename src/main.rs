@@ -1,4 +1,4 @@
-mod pi_vs_pi;
+use pi_vs_pi::pi_vs_pi;
 
 fn main() {
     pi_vs_pi::run_pi_vs_pi_app();
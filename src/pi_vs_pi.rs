@@ -5,112 +5,215 @@ use bevy::input::ButtonInput; // For explicit ButtonInput usage
 // Optionally, for Input alias:
 // use bevy::input::ButtonInput as Input;
 use bevy::window::WindowResolution;
-use bevy::render::render_asset::RenderAssetUsages;
 use std::f32::consts::PI;
+use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 
+pub mod chart;
+pub mod csv_import;
+pub mod data_table;
+pub mod dataset;
+pub mod hot_reload;
+pub mod picking;
+pub mod radius;
+pub mod scoreboard;
+pub mod tooltip;
+
+use chart::{slice_angles, ChartSpec, SliceLayout};
+use csv_import::{ColumnRef, CsvMapping, HeaderMode};
+use radius::RadiusConfig;
+
+use crate::tiny_text::TinyTextPlugin;
+
 // =========================
 // 1. Data Model & Constants
 // =========================
+/// One category compared across all entities, one value per entity.
+/// Two-entity files may use `left`/`right` (or the legacy `kobold`/`troglodyte`)
+/// instead of `values`.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(try_from = "dataset::RawCompetitionData")]
 pub struct CompetitionData {
     pub name: String,
-    pub kobold: f32,
-    pub troglodyte: f32,
+    pub values: Vec<f32>,
+}
+
+impl CompetitionData {
+    pub fn new(name: impl Into<String>, values: Vec<f32>) -> Self {
+        Self { name: name.into(), values }
+    }
+
+    /// Sum of every entity's value in this category
+    pub fn total(&self) -> f32 {
+        self.values.iter().sum()
+    }
+}
+
+/// A compared side: display name, scoreboard color and optional description
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EntityDef {
+    pub name: String,
+    #[serde(with = "dataset::hex_color")]
+    pub color: Color,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl EntityDef {
+    pub fn new(name: impl Into<String>, color: Color) -> Self {
+        Self { name: name.into(), color, description: None }
+    }
+
+    /// The original Kobold vs Troglodyte pairing, used for legacy datasets
+    pub fn legacy_pair() -> Vec<EntityDef> {
+        vec![
+            EntityDef::new("Kobolds", ENTITY_COLORS[0]),
+            EntityDef::new("Troglodytes", ENTITY_COLORS[1]),
+        ]
+    }
+
+    /// Default scoreboard color for the entity at `index`
+    pub fn default_color(index: usize) -> Color {
+        ENTITY_COLORS[index % ENTITY_COLORS.len()]
+    }
+}
+
+/// A full dataset: the compared entities and their categories
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Dataset {
+    pub entities: Vec<EntityDef>,
+    pub categories: Vec<CompetitionData>,
+    /// How slice radii are derived from category shares
+    #[serde(default)]
+    pub radius: RadiusConfig,
 }
 
 #[derive(Resource)]
 pub struct GameData {
+    pub entities: Vec<EntityDef>,
     pub data: Vec<CompetitionData>,
     pub colors: Vec<Color>,
+    pub radius: RadiusConfig,
     pub selected_category: usize,
-    pub left_angle: f32,
-    pub right_angle: f32,
-    pub target_right_angle: f32,
+}
+
+impl GameData {
+    /// Wrap a loaded dataset with the default palette and initial selection
+    pub fn new(dataset: Dataset) -> Self {
+        let colors = vec![
+            Color::rgb(1.0, 0.27, 0.27),  // Red
+            Color::rgb(0.27, 1.0, 0.27),  // Green
+            Color::rgb(0.27, 0.27, 1.0),  // Blue
+            Color::rgb(1.0, 1.0, 0.27),   // Yellow
+            Color::rgb(1.0, 0.27, 1.0),   // Magenta
+            Color::rgb(0.27, 1.0, 1.0),   // Cyan
+            Color::rgb(1.0, 0.53, 0.27),  // Orange
+            Color::rgb(0.53, 1.0, 0.27),  // Light Green
+        ];
+
+        GameData {
+            entities: dataset.entities,
+            data: dataset.categories,
+            radius: dataset.radius,
+            colors,
+            selected_category: 0,
+        }
+    }
+
+    pub fn entity_count(&self) -> usize {
+        self.entities.len()
+    }
+}
+
+/// Chart rotation, kept apart from `GameData` so the per-frame easing of the
+/// follower charts does not mark the data and selection as changed
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct ChartRotation {
+    /// Current rotation of each entity's chart; chart 0 is driven by the user
+    pub angles: Vec<f32>,
+    /// Rotation the other charts ease toward to face the selected category
+    pub targets: Vec<f32>,
+}
+
+impl ChartRotation {
+    pub fn new(entity_count: usize) -> Self {
+        let mut angles = vec![0.0; entity_count];
+        angles[0] = -PI / 2.0;
+        Self { angles, targets: vec![0.0; entity_count] }
+    }
 }
 
 const BASE_RADIUS: f32 = 9.0; // For variable-radius algorithm
 const PIE_HEIGHT: f32 = 1.0;
+/// Supported number of compared entities (one pie chart each)
+pub const MIN_ENTITIES: usize = 2;
+pub const MAX_ENTITIES: usize = 8;
+
+/// Default entity colors: red and teal for the original pair, then distinct hues
+const ENTITY_COLORS: [Color; MAX_ENTITIES] = [
+    Color::rgb(1.0, 0.42, 0.42),  // Red (Kobolds)
+    Color::rgb(0.31, 0.80, 0.77), // Teal (Troglodytes)
+    Color::rgb(1.0, 0.85, 0.24),  // Gold
+    Color::rgb(0.42, 0.80, 0.47), // Green
+    Color::rgb(0.30, 0.59, 1.0),  // Blue
+    Color::rgb(0.78, 0.49, 1.0),  // Violet
+    Color::rgb(1.0, 0.62, 0.27),  // Orange
+    Color::rgb(0.95, 0.36, 0.71), // Pink
+];
 
 // =========================
 // 2. Visualization Core
 // =========================
-/// Calculate the radius for a given category and entity (kobold/left or troglodyte/right)
-fn calculate_slice_radius(data: &CompetitionData, is_left: bool) -> f32 {
-    let category_total = data.kobold + data.troglodyte;
-    if category_total == 0.0 { return BASE_RADIUS * 0.5; }
-    let entity_value = if is_left { data.kobold } else { data.troglodyte };
-    BASE_RADIUS * (entity_value / category_total)
+/// Chart centers for `count` entities: one row up to four charts, two rows beyond
+fn chart_layout(count: usize) -> Vec<Vec3> {
+    const LAYOUT_WIDTH: f32 = 25.0;
+    const CENTER_Y: f32 = 7.2;
+    const ROW_SPACING: f32 = 9.0;
+
+    let rows = if count <= 4 { 1 } else { 2 };
+    let cols = count.div_ceil(rows);
+    let cell_width = LAYOUT_WIDTH / cols as f32;
+    (0..count)
+        .map(|i| {
+            let (row, col) = (i / cols, i % cols);
+            // Center a short last row
+            let in_row = if row + 1 == rows { count - row * cols } else { cols };
+            let x = (col as f32 - (in_row as f32 - 1.0) / 2.0) * cell_width;
+            let y = CENTER_Y + ((rows as f32 - 1.0) / 2.0 - row as f32) * ROW_SPACING;
+            Vec3::new(x, y, 0.0)
+        })
+        .collect()
 }
 
-// =========================
-// 3. Chart Generation
-// =========================
-fn create_chart(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-    data: &[CompetitionData],
-    colors: &[Color],
-    position: Vec3,
-    is_left: bool,
-) {
-    let total: f32 = data.iter().map(|d| if is_left { d.kobold } else { d.troglodyte }).sum();
-    let mut angle = 0.0;
-    for (i, entry) in data.iter().enumerate() {
-        let value = if is_left { entry.kobold } else { entry.troglodyte };
-        let slice_angle = if total > 0.0 { (value / total) * 2.0 * PI } else { 0.0 };
-        let color = colors[i % colors.len()];
-        let slice_radius = calculate_slice_radius(entry, is_left);
-        let slice_mesh = create_pie_slice_mesh(angle, angle + slice_angle, slice_radius, PIE_HEIGHT);
-        let material = materials.add(StandardMaterial {
-            base_color: color,
-            unlit: false,
-            ..default()
-        });
-        let mut entity = commands.spawn(PbrBundle {
-            mesh: meshes.add(slice_mesh),
-            material,
-            transform: Transform::from_translation(position),
-            ..default()
-        });
-        entity.insert(PieSlice {
-            category_index: i,
-            start_angle: angle,
-            end_angle: angle + slice_angle,
-            is_left,
-        });
-        if is_left {
-            entity.insert(LeftChart);
-        } else {
-            entity.insert(RightChart);
-        }
-        angle += slice_angle;
-    }
-}
-// ... (rest of file continues unchanged for now)
-
-
 // Component markers
 #[derive(Component)]
-struct LeftChart;
-
-#[derive(Component)]
-struct RightChart;
-
-#[derive(Component)]
-struct PieSlice {
+pub struct PieSlice {
     category_index: usize,
     start_angle: f32,
     end_angle: f32,
-    is_left: bool,
+    radius: f32,
+    entity_index: usize,
 }
 
-#[derive(Component)]
-struct ScoreBoard;
+impl PieSlice {
+    /// Whether the slice's category and entity still exist. After a reload the
+    /// old slices live on until `rebuild_charts`' despawns are applied, so
+    /// systems running in between skip the ones that no longer fit the data.
+    fn is_current(&self, game_data: &GameData) -> bool {
+        self.category_index < game_data.data.len() && self.entity_index < game_data.entity_count()
+    }
+}
 
-#[derive(Component)]
-struct CategorySelector;
+/// Request to despawn every pie slice and rebuild the charts from `GameData`
+#[derive(Event)]
+pub struct RebuildCharts;
+
+/// One category value was edited; only the slices it affects are reshaped
+#[derive(Event)]
+pub struct SliceValueChanged {
+    pub category_index: usize,
+    pub entity_index: usize,
+}
 
 // Input state
 #[derive(Resource, Default)]
@@ -120,8 +223,102 @@ struct InputState {
     drag_start_angle: f32,
 }
 
+/// Command-line launch options
+#[derive(Debug, Default)]
+pub struct LaunchOptions {
+    pub dataset: Option<PathBuf>,
+    pub csv_mapping: CsvMapping,
+    pub score_tween: scoreboard::ScoreTween,
+}
+
+const USAGE: &str = "usage: pi-vs-pi [DATASET.json | DATASET.csv] [--name COL] [--left COL] [--right COL]
+                 [--values COL,COL,...] [--header | --no-header]
+                 [--presentation] [--count-up SECONDS] [--easing NAME]
+  COL is a 1-based column number or a header name (CSV only)
+  --values picks one column per entity (2-8) and replaces --left/--right
+  --presentation counts scoreboard numbers up to new values; --count-up sets
+  the duration and --easing one of linear, ease-in, ease-out, ease-in-out";
+
+/// Parses the arguments after the program name. An empty error asks for the
+/// usage text alone.
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<LaunchOptions, String> {
+    let mut options = LaunchOptions::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut column = |flag: &str| {
+            args.next()
+                .map(|spec| ColumnRef::parse(&spec))
+                .ok_or_else(|| format!("{} expects a column", flag))
+        };
+        match arg.as_str() {
+            "--name" => options.csv_mapping.name = column("--name")?,
+            "--left" => options.csv_mapping.values[0] = column("--left")?,
+            "--right" => {
+                let right = column("--right")?;
+                // A one-column --values list has no right column to replace
+                let values = &mut options.csv_mapping.values;
+                values.resize(values.len().max(2), ColumnRef::Index(2));
+                values[1] = right;
+            }
+            "--values" => {
+                let list = args.next().ok_or("--values expects a column list")?;
+                options.csv_mapping.values = list.split(',').map(ColumnRef::parse).collect();
+            }
+            "--header" => options.csv_mapping.header = HeaderMode::Present,
+            "--no-header" => options.csv_mapping.header = HeaderMode::Absent,
+            "--presentation" => options.score_tween.enabled = true,
+            "--count-up" => {
+                options.score_tween.duration = args
+                    .next()
+                    .and_then(|seconds| seconds.parse::<f32>().ok())
+                    .filter(|seconds| *seconds >= 0.0)
+                    .ok_or("--count-up expects a duration in seconds")?;
+            }
+            "--easing" => {
+                options.score_tween.easing = args
+                    .next()
+                    .and_then(|name| scoreboard::Easing::parse(&name))
+                    .ok_or("--easing expects linear, ease-in, ease-out or ease-in-out")?;
+            }
+            "-h" | "--help" => return Err(String::new()),
+            flag if flag.starts_with("--") => return Err(format!("unknown option {}", flag)),
+            path if options.dataset.is_none() => options.dataset = Some(PathBuf::from(path)),
+            extra => return Err(format!("unexpected argument {}", extra)),
+        }
+    }
+    Ok(options)
+}
+
 pub fn run_pi_vs_pi_app() {
-    App::new()
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            if !message.is_empty() {
+                eprintln!("{}", message);
+            }
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    // Fall back to the built-in demo only when no dataset path is given
+    let game_data = match &options.dataset {
+        Some(path) => match dataset::load_dataset(path, &options.csv_mapping) {
+            Ok(data) => GameData::new(data),
+            Err(err) => {
+                eprintln!("Failed to load dataset {}: {}", path.display(), err);
+                std::process::exit(1);
+            }
+        },
+        None => create_game_data(),
+    };
+
+    let mut app = App::new();
+    if let Some(path) = options.dataset {
+        app.insert_resource(hot_reload::DatasetSource::new(path, options.csv_mapping));
+    }
+
+    app
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
                 title: "Pi vs Pi - Rust Edition".into(),
@@ -130,48 +327,53 @@ pub fn run_pi_vs_pi_app() {
             }),
             ..default()
         }))
-        .insert_resource(create_game_data())
+        .add_plugins(TinyTextPlugin)
+        .insert_resource(ChartRotation::new(game_data.entity_count()))
+        .insert_resource(game_data)
+        .insert_resource(ChartSpec::default())
+        .insert_resource(options.score_tween)
         .insert_resource(InputState::default())
-        .add_systems(Startup, setup)
+        .insert_resource(picking::SlicePicker::default())
+        .insert_resource(data_table::DataTableState::default())
+        .add_event::<RebuildCharts>()
+        .add_event::<SliceValueChanged>()
+        .add_systems(Startup, (setup, scoreboard::setup_stadium, data_table::spawn_data_table, tooltip::spawn_tooltip))
         .add_systems(Update, (
             handle_input,
             update_charts,
-            update_ui,
+            scoreboard::update_scoreboards,
             handle_mouse_drag,
+            hot_reload::watch_dataset_file,
+            radius::toggle_radius_strategy,
+            chart::cycle_mesh_quality,
+            picking::pick_slices,
+            tooltip::update_tooltip,
+            rebuild_charts,
+            scoreboard::rebuild_stadium,
+            refresh_changed_slices,
+            data_table::rebuild_table_rows,
+            data_table::handle_table_clicks,
+            data_table::handle_cell_typing,
+            data_table::refresh_table_view,
         ))
         .run();
 }
 
+/// Built-in demo dataset used when no dataset path is given
 fn create_game_data() -> GameData {
-    let data = vec![
-        CompetitionData { name: "Strength".to_string(), kobold: 4.0, troglodyte: 7.0 },
-        CompetitionData { name: "Cunning".to_string(), kobold: 8.0, troglodyte: 5.0 },
-        CompetitionData { name: "Aggression".to_string(), kobold: 6.0, troglodyte: 8.0 },
-        CompetitionData { name: "Sociality".to_string(), kobold: 7.0, troglodyte: 3.0 },
-        CompetitionData { name: "Habitat".to_string(), kobold: 6.0, troglodyte: 9.0 },
-        CompetitionData { name: "Intelligence".to_string(), kobold: 7.0, troglodyte: 4.0 },
-        CompetitionData { name: "Stealth".to_string(), kobold: 8.0, troglodyte: 6.0 },
-    ];
-
-    let colors = vec![
-        Color::rgb(1.0, 0.27, 0.27),  // Red
-        Color::rgb(0.27, 1.0, 0.27),  // Green
-        Color::rgb(0.27, 0.27, 1.0),  // Blue
-        Color::rgb(1.0, 1.0, 0.27),   // Yellow
-        Color::rgb(1.0, 0.27, 1.0),   // Magenta
-        Color::rgb(0.27, 1.0, 1.0),   // Cyan
-        Color::rgb(1.0, 0.53, 0.27),  // Orange
-        Color::rgb(0.53, 1.0, 0.27),  // Light Green
-    ];
-
-    GameData {
-        data,
-        colors,
-        selected_category: 0,
-        left_angle: -PI / 2.0,
-        right_angle: 0.0,
-        target_right_angle: 0.0,
-    }
+    GameData::new(Dataset {
+        radius: RadiusConfig::default(),
+        entities: EntityDef::legacy_pair(),
+        categories: vec![
+            CompetitionData::new("Strength", vec![4.0, 7.0]),
+            CompetitionData::new("Cunning", vec![8.0, 5.0]),
+            CompetitionData::new("Aggression", vec![6.0, 8.0]),
+            CompetitionData::new("Sociality", vec![7.0, 3.0]),
+            CompetitionData::new("Habitat", vec![6.0, 9.0]),
+            CompetitionData::new("Intelligence", vec![7.0, 4.0]),
+            CompetitionData::new("Stealth", vec![8.0, 6.0]),
+        ],
+    })
 }
 
 fn setup(
@@ -179,11 +381,17 @@ fn setup(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     game_data: Res<GameData>,
+    chart_spec: Res<ChartSpec>,
 ) {
-    // Camera
+    // Camera (the web version's 75 degree field of view keeps the scoreboards in frame)
     commands.spawn(Camera3dBundle {
         transform: Transform::from_xyz(0.0, 5.0, 25.0)
             .looking_at(Vec3::new(0.0, 4.0, 0.0), Vec3::Y),
+        projection: PerspectiveProjection {
+            fov: 75f32.to_radians(),
+            ..default()
+        }
+        .into(),
         ..default()
     });
 
@@ -210,247 +418,113 @@ fn setup(
     });
 
     // Create initial charts
-    create_pie_charts(&mut commands, &mut meshes, &mut materials, &game_data);
-    create_scoreboards(&mut commands, &game_data);
+    create_pie_charts(&mut commands, &mut meshes, &mut materials, &game_data, &chart_spec);
 }
 
-/// Spawns both left and right variable-radius pie charts for comparative visualization.
-fn create_pie_charts(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-    game_data: &GameData,
+/// Replaces every pie slice when `RebuildCharts` is sent (dataset reloads, edits)
+pub fn rebuild_charts(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut events: EventReader<RebuildCharts>,
+    game_data: Res<GameData>,
+    chart_spec: Res<ChartSpec>,
+    slices: Query<Entity, With<PieSlice>>,
 ) {
-    // Left chart (Kobolds)
-    create_chart(
-        commands,
-        meshes,
-        materials,
-        &game_data.data,
-        &game_data.colors,
-        Vec3::new(-6.25, 7.2, 0.0),
-        true,
-    );
-
-    // Right chart (Troglodytes)
-    create_chart(
-        commands,
-        meshes,
-        materials,
-        &game_data.data,
-        &game_data.colors,
-        Vec3::new(6.25, 7.2, 0.0),
-        false,
-    );
+    if events.read().count() == 0 {
+        return;
+    }
+    for entity in slices.iter() {
+        commands.entity(entity).despawn();
+    }
+    create_pie_charts(&mut commands, &mut meshes, &mut materials, &game_data, &chart_spec);
 }
 
-// =========================
-// 4. Scoreboard System
-// =========================
-// (No major logic change, but add section marker for clarity)
-
-
-fn create_chart_alt(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-    values: &[f32],
-    colors: &[Color],
-    position: Vec3,
-    is_left: bool,
-    data: &[CompetitionData],
+/// Reshapes the slices affected by an edited value without respawning them:
+/// every slice on the edited entity's chart (their angles shift) and the
+/// edited category's slice on every chart (its radius shares shift)
+fn refresh_changed_slices(
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut events: EventReader<SliceValueChanged>,
+    game_data: Res<GameData>,
+    chart_spec: Res<ChartSpec>,
+    mut slices: Query<(&mut PieSlice, &Handle<Mesh>)>,
 ) {
-    let total: f32 = values.iter().sum();
-    let mut angle = 0.0;
-
-    for (i, &value) in values.iter().enumerate() {
-        let slice_angle = (value / total) * 2.0 * PI;
-        let color = colors[i % colors.len()];
-        
-        // Calculate variable radius based on competitive proportion
-        let other_value = if is_left { data[i].troglodyte } else { data[i].kobold };
-        let category_total = value + other_value;
-        let base_radius = 9.0;
-        let slice_radius = base_radius * (value / category_total);
-
-        // Create slice mesh
-        let slice_mesh = create_pie_slice_mesh(angle, angle + slice_angle, slice_radius, 1.5);
-        let material = materials.add(StandardMaterial {
-            base_color: color,
-            alpha_mode: AlphaMode::Blend,
-            ..default()
-        });
-
-        let mut entity = commands.spawn(PbrBundle {
-            mesh: meshes.add(slice_mesh),
-            material,
-            transform: Transform::from_translation(position),
-            ..default()
-        });
-
-        entity.insert(PieSlice {
-            category_index: i,
-            start_angle: angle,
-            end_angle: angle + slice_angle,
-            is_left,
-        });
-
-        if is_left {
-            entity.insert(LeftChart);
-        } else {
-            entity.insert(RightChart);
+    for event in events.read() {
+        if event.category_index >= game_data.data.len() || event.entity_index >= game_data.entity_count() {
+            continue;
+        }
+        let spans = slice_angles(&game_data.data, event.entity_index);
+        for (mut slice, mesh) in slices.iter_mut() {
+            if !slice.is_current(&game_data) {
+                continue;
+            }
+            let on_edited_chart = slice.entity_index == event.entity_index;
+            if !on_edited_chart && slice.category_index != event.category_index {
+                continue;
+            }
+            if on_edited_chart {
+                (slice.start_angle, slice.end_angle) = spans[slice.category_index];
+            }
+            slice.radius = chart::slice_radius(
+                &game_data.data[slice.category_index],
+                slice.entity_index,
+                &game_data.radius,
+                chart_spec.base_radius,
+            );
+            let layout = SliceLayout {
+                category_index: slice.category_index,
+                start_angle: slice.start_angle,
+                end_angle: slice.end_angle,
+                radius: slice.radius,
+            };
+            meshes.insert(mesh.id(), chart::create_pie_slice_mesh(&layout, &chart_spec));
         }
-
-        angle += slice_angle;
-    }
-}
-
-fn create_pie_slice_mesh(start_angle: f32, end_angle: f32, radius: f32, height: f32) -> Mesh {
-    let segments = 32;
-    let angle_step = (end_angle - start_angle) / segments as f32;
-    
-    let mut positions = Vec::new();
-    let mut normals = Vec::new();
-    let mut indices = Vec::new();
-
-    // Center points for top and bottom
-    positions.push([0.0, height / 2.0, 0.0]); // Top center
-    positions.push([0.0, -height / 2.0, 0.0]); // Bottom center
-    normals.push([0.0, 1.0, 0.0]);
-    normals.push([0.0, -1.0, 0.0]);
-
-    // Edge vertices
-    for i in 0..=segments {
-        let angle = start_angle + i as f32 * angle_step;
-        let x = radius * angle.cos();
-        let z = radius * angle.sin();
-        
-        // Top edge
-        positions.push([x, height / 2.0, z]);
-        normals.push([0.0, 1.0, 0.0]);
-        
-        // Bottom edge
-        positions.push([x, -height / 2.0, z]);
-        normals.push([0.0, -1.0, 0.0]);
-    }
-
-    // Top face triangles
-    for i in 0..segments {
-        let v1 = 2 + i * 2;
-        let v2 = 2 + (i + 1) * 2;
-        indices.extend_from_slice(&[0, v1 as u32, v2 as u32]);
-    }
-
-    // Bottom face triangles
-    for i in 0..segments {
-        let v1 = 3 + i * 2;
-        let v2 = 3 + (i + 1) * 2;
-        indices.extend_from_slice(&[1, v2 as u32, v1 as u32]);
-    }
-
-    // Side faces
-    for i in 0..segments {
-        let top1 = 2 + i * 2;
-        let bottom1 = 3 + i * 2;
-        let top2 = 2 + (i + 1) * 2;
-        let bottom2 = 3 + (i + 1) * 2;
-        
-        // Two triangles per segment
-        indices.extend_from_slice(&[
-            top1 as u32, bottom1 as u32, top2 as u32,
-            bottom1 as u32, bottom2 as u32, top2 as u32,
-        ]);
     }
-
-    let mut mesh = Mesh::new(
-        bevy::render::render_resource::PrimitiveTopology::TriangleList,
-        RenderAssetUsages::default(),
-    );
-    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
-    mesh.insert_indices(bevy::render::mesh::Indices::U32(indices));
-    mesh
 }
 
-fn create_scoreboards(commands: &mut Commands, game_data: &GameData) {
-    let selected = &game_data.data[game_data.selected_category];
-    
-    // Left scoreboard (Kobolds)
-    create_scoreboard_text(commands, "KOBOLDS", selected.kobold as i32, 
-                          Vec3::new(-6.25, 16.0, 0.0), Color::rgb(1.0, 0.42, 0.42));
-    
-    // Center scoreboard (Category name and difference)
-    let diff = (selected.kobold - selected.troglodyte).abs() as i32;
-    create_scoreboard_text(commands, &selected.name, diff, 
-                          Vec3::new(0.0, 16.0, 0.0), Color::rgb(1.0, 1.0, 0.33));
-    
-    // Right scoreboard (Troglodytes)
-    create_scoreboard_text(commands, "TROGLODYTES", selected.troglodyte as i32, 
-                          Vec3::new(6.25, 16.0, 0.0), Color::rgb(0.31, 0.80, 0.77));
-}
-
-fn create_scoreboard_text(
+/// Spawns one variable-radius pie chart per entity for comparative visualization.
+fn create_pie_charts(
     commands: &mut Commands,
-    title: &str,
-    value: i32,
-    position: Vec3,
-    color: Color,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    game_data: &GameData,
+    spec: &ChartSpec,
 ) {
-    commands.spawn((
-        Text2dBundle {
-            text: Text {
-                sections: vec![
-                    TextSection::new(
-                        format!("{}\n{}", title, value),
-                        TextStyle {
-                            font_size: 32.0,
-                            color,
-                            ..default()
-                        },
-                    ),
-                ],
-                justify: bevy::text::JustifyText::Center,
-                ..default()
-            },
-            transform: Transform::from_translation(position),
-            ..default()
-        },
-        ScoreBoard,
-    ));
+    for (entity_index, position) in chart_layout(game_data.entity_count()).into_iter().enumerate() {
+        chart::spawn_chart(commands, meshes, materials, game_data, &spec.at(position), entity_index);
+    }
 }
 
 fn handle_input(
     mut game_data: ResMut<GameData>,
+    mut rotation: ResMut<ChartRotation>,
     mut wheel_events: EventReader<MouseWheel>,
     keyboard: Res<ButtonInput<KeyCode>>,
 ) {
     // Mouse wheel input
     for event in wheel_events.read() {
-        game_data.left_angle += event.y * 0.01;
-        update_selection(&mut game_data);
+        rotation.angles[0] += event.y * 0.01;
+        update_selection(&mut game_data, &mut rotation);
     }
 
     // Keyboard input for category selection
+    let count = game_data.data.len();
     if keyboard.just_pressed(KeyCode::ArrowLeft) || keyboard.just_pressed(KeyCode::ArrowUp) {
-        game_data.selected_category = if game_data.selected_category == 0 {
-            game_data.data.len() - 1
-        } else {
-            game_data.selected_category - 1
-        };
-        let selected = game_data.selected_category;
-        select_category(&mut game_data, selected);
+        let selected = (game_data.selected_category + count - 1) % count;
+        select_category(&mut game_data, &mut rotation, selected);
     }
     
     if keyboard.just_pressed(KeyCode::ArrowRight) || keyboard.just_pressed(KeyCode::ArrowDown) {
-        game_data.selected_category = (game_data.selected_category + 1) % game_data.data.len();
-        let selected = game_data.selected_category;
-        select_category(&mut game_data, selected);
+        let selected = (game_data.selected_category + 1) % count;
+        select_category(&mut game_data, &mut rotation, selected);
     }
 }
 
 fn handle_mouse_drag(
     mut input_state: ResMut<InputState>,
     mut game_data: ResMut<GameData>,
+    mut rotation: ResMut<ChartRotation>,
     mouse_button: Res<ButtonInput<MouseButton>>,
     mut mouse_motion: EventReader<MouseMotion>,
     windows: Query<&Window>,
@@ -459,7 +533,7 @@ fn handle_mouse_drag(
     
     if mouse_button.just_pressed(MouseButton::Left) {
         input_state.dragging = true;
-        input_state.drag_start_angle = game_data.left_angle;
+        input_state.drag_start_angle = rotation.angles[0];
         if let Some(cursor_pos) = window.cursor_position() {
             input_state.drag_start_y = cursor_pos.y;
         }
@@ -473,118 +547,66 @@ fn handle_mouse_drag(
         for motion in mouse_motion.read() {
             let screen_height = window.height();
             let rotation_amount = (motion.delta.y / screen_height) * 2.0 * PI;
-            game_data.left_angle += rotation_amount;
-            update_selection(&mut game_data);
+            rotation.angles[0] += rotation_amount;
+            update_selection(&mut game_data, &mut rotation);
         }
     }
 }
 
-fn update_selection(game_data: &mut GameData) {
-    // Find which category is currently selected based on angle
-    let total: f32 = game_data.data.iter().map(|d| d.kobold).sum();
-    let mut angle = 0.0;
-    let normalized_angle = ((-game_data.left_angle) % (2.0 * PI) + 2.0 * PI) % (2.0 * PI);
-    
-    for (i, data_item) in game_data.data.iter().enumerate() {
-        let slice_angle = (data_item.kobold / total) * 2.0 * PI;
-        if normalized_angle >= angle && normalized_angle < angle + slice_angle {
-            game_data.selected_category = i;
-            break;
-        }
-        angle += slice_angle;
+/// Selects the category under the driven chart's pointer. `GameData` is only
+/// written when the selection actually moves to another category.
+fn update_selection(game_data: &mut ResMut<GameData>, rotation: &mut ChartRotation) {
+    // Find which category is currently selected based on the driven chart's angle
+    let normalized_angle = (-rotation.angles[0]).rem_euclid(2.0 * PI);
+    let under_pointer = slice_angles(&game_data.data, 0)
+        .into_iter()
+        .position(|(start, end)| normalized_angle >= start && normalized_angle < end);
+    if let Some(selected) = under_pointer.filter(|&i| i != game_data.selected_category) {
+        game_data.selected_category = selected;
     }
     
-    // Calculate target angle for right chart alignment
-    find_target_angle(game_data);
+    // Calculate target angles so every other chart faces the same category
+    find_target_angles(game_data, rotation);
 }
 
-fn select_category(game_data: &mut GameData, category_index: usize) {
-    let total: f32 = game_data.data.iter().map(|d| d.kobold).sum();
-    let mut angle = 0.0;
-    
-    for (i, data_item) in game_data.data.iter().enumerate() {
-        let slice_angle = (data_item.kobold / total) * 2.0 * PI;
-        if i == category_index {
-            let mid_angle = angle + slice_angle / 2.0;
-            game_data.left_angle = -mid_angle;
-            break;
-        }
-        angle += slice_angle;
-    }
-    
-    update_selection(game_data);
+/// Selects a category and turns the driven chart to center it
+fn select_category(game_data: &mut GameData, rotation: &mut ChartRotation, category_index: usize) {
+    let (start, end) = slice_angles(&game_data.data, 0)[category_index];
+    rotation.angles[0] = -(start + end) / 2.0;
+    game_data.selected_category = category_index;
+    find_target_angles(game_data, rotation);
 }
 
-fn find_target_angle(game_data: &mut GameData) {
-    let total: f32 = game_data.data.iter().map(|d| d.troglodyte).sum();
-    let mut angle = 0.0;
-    
-    // Right chart is in reverse order
-    let right_values: Vec<f32> = game_data.data.iter().rev().map(|d| d.troglodyte).collect();
-    let target_index = game_data.data.len() - 1 - game_data.selected_category;
-    
-    for (i, &value) in right_values.iter().enumerate() {
-        let slice_angle = (value / total) * 2.0 * PI;
-        if i == target_index {
-            let mid_angle = angle + slice_angle / 2.0;
-            game_data.target_right_angle = PI - mid_angle;
-            break;
-        }
-        angle += slice_angle;
+fn find_target_angles(game_data: &GameData, rotation: &mut ChartRotation) {
+    // Each follower chart turns the selected slice's midpoint toward the driven chart
+    for entity in 1..game_data.entity_count() {
+        let (start, end) = slice_angles(&game_data.data, entity)[game_data.selected_category];
+        rotation.targets[entity] = PI - (start + end) / 2.0;
     }
 }
 
-fn update_charts(
-    mut game_data: ResMut<GameData>,
-    mut left_query: Query<&mut Transform, (With<LeftChart>, Without<RightChart>)>,
-    mut right_query: Query<&mut Transform, (With<RightChart>, Without<LeftChart>)>,
+/// Eases the follower charts toward their targets and turns every slice to its
+/// chart's angle, writing only the transforms whose rotation changed
+pub fn update_charts(
+    mut rotation: ResMut<ChartRotation>,
+    mut slice_query: Query<(&PieSlice, &mut Transform)>,
 ) {
-    // Update left chart rotation
-    for mut transform in left_query.iter_mut() {
-        transform.rotation = Quat::from_rotation_z(game_data.left_angle);
+    // Smoothly rotate follower charts to their target angles
+    let rotation = &mut *rotation;
+    for (angle, target) in rotation.angles.iter_mut().zip(&rotation.targets).skip(1) {
+        let angle_diff = target - *angle;
+        let normalized_diff = (angle_diff + PI).rem_euclid(2.0 * PI) - PI;
+        *angle += normalized_diff * 0.1;
     }
     
-    // Smoothly rotate right chart to target angle
-    let angle_diff = game_data.target_right_angle - game_data.right_angle;
-    let normalized_diff = ((angle_diff + PI) % (2.0 * PI)) - PI;
-    game_data.right_angle += normalized_diff * 0.1;
-    
-    for mut transform in right_query.iter_mut() {
-        transform.rotation = Quat::from_rotation_z(game_data.right_angle);
+    for (slice, mut transform) in slice_query.iter_mut() {
+        // Slices of an entity dropped by a reload are about to be despawned
+        let Some(&angle) = rotation.angles.get(slice.entity_index) else {
+            continue;
+        };
+        let target = Quat::from_rotation_z(angle);
+        if transform.rotation != target {
+            transform.rotation = target;
+        }
     }
 }
-
-fn update_ui(
-    mut commands: Commands,
-    game_data: Res<GameData>,
-    scoreboard_query: Query<Entity, With<ScoreBoard>>,
-) {
-    if !game_data.is_changed() {
-        return;
-    }
-    
-    // Remove old scoreboards
-    for entity in scoreboard_query.iter() {
-        commands.entity(entity).despawn();
-    }
-    
-    // Create new scoreboards
-    let selected = &game_data.data[game_data.selected_category];
-    
-    // Left scoreboard (Kobolds)
-    create_scoreboard_text(&mut commands, "KOBOLDS", selected.kobold as i32, 
-                          Vec3::new(-6.25, 16.0, 0.0), Color::rgb(1.0, 0.42, 0.42));
-    
-    // Center scoreboard (Category name and difference)
-    let diff = (selected.kobold - selected.troglodyte).abs() as i32;
-    create_scoreboard_text(&mut commands, &selected.name, diff, 
-                          Vec3::new(0.0, 16.0, 0.0), Color::rgb(1.0, 1.0, 0.33));
-    
-    // Right scoreboard (Troglodytes)
-    create_scoreboard_text(&mut commands, "TROGLODYTES", selected.troglodyte as i32, 
-                          Vec3::new(6.25, 16.0, 0.0), Color::rgb(0.31, 0.80, 0.77));
-    
-    // Print current selection to console for debugging
-    println!("Selected: {} - Kobold: {}, Troglodyte: {}, Diff: {}", 
-             selected.name, selected.kobold, selected.troglodyte, diff);
-}
\ No newline at end of file
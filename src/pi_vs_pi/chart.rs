@@ -0,0 +1,339 @@
+//! Pie chart construction.
+// Every chart is built from a `ChartSpec` describing where it sits, how thick
+// and wide its slices are, how finely the curved edge is tessellated and how
+// the slices are shaded. Tessellation is adaptive: each slice gets just enough
+// segments to keep its curved edge within a chord error tolerance, so thin
+// slices in large datasets cost a single segment. Empty data is handled up
+// front: an entity whose values are all zero gets an even split so its chart
+// can still be rotated and selected, and zero-width or zero-radius slices get
+// an empty mesh rather than degenerate triangles.
+
+use std::f32::consts::PI;
+
+use bevy::input::keyboard::KeyCode;
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+
+use super::data_table::DataTableState;
+use super::radius::RadiusConfig;
+use super::{CompetitionData, GameData, PieSlice, RebuildCharts, BASE_RADIUS, PIE_HEIGHT};
+
+/// Surface options shared by every slice of a chart
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SliceMaterial {
+    /// 1.0 is opaque; anything lower is alpha blended
+    pub opacity: f32,
+    pub metallic: f32,
+    pub perceptual_roughness: f32,
+    pub unlit: bool,
+}
+
+impl Default for SliceMaterial {
+    fn default() -> Self {
+        Self {
+            opacity: 1.0,
+            metallic: 0.0,
+            perceptual_roughness: 0.5,
+            unlit: false,
+        }
+    }
+}
+
+impl SliceMaterial {
+    pub fn standard_material(&self, color: Color) -> StandardMaterial {
+        let opacity = self.opacity.clamp(0.0, 1.0);
+        StandardMaterial {
+            base_color: color.with_a(opacity),
+            alpha_mode: if opacity < 1.0 { AlphaMode::Blend } else { AlphaMode::Opaque },
+            metallic: self.metallic,
+            perceptual_roughness: self.perceptual_roughness,
+            unlit: self.unlit,
+            ..default()
+        }
+    }
+}
+
+/// Global mesh detail level, scaling the chord error tolerance of every chart
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum MeshQuality {
+    /// 4x the tolerance, for very large datasets
+    Low,
+    #[default]
+    Medium,
+    /// A quarter of the tolerance, for close-ups and screenshots
+    High,
+}
+
+impl MeshQuality {
+    const ALL: [MeshQuality; 3] = [MeshQuality::Low, MeshQuality::Medium, MeshQuality::High];
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|q| *q == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    fn chord_error_scale(self) -> f32 {
+        match self {
+            MeshQuality::Low => 4.0,
+            MeshQuality::Medium => 1.0,
+            MeshQuality::High => 0.25,
+        }
+    }
+}
+
+/// Placement, geometry and material of one pie chart.
+/// The resource holds the shared style; each chart gets its own `position`.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct ChartSpec {
+    pub position: Vec3,
+    /// Slice thickness along the chart's axis
+    pub height: f32,
+    /// Radius of a slice holding the whole category total (before the radius strategy)
+    pub base_radius: f32,
+    /// Largest allowed gap between a slice's curved edge and its straight segments
+    pub max_chord_error: f32,
+    /// Upper bound on segments per slice regardless of tolerance
+    pub max_segments: u32,
+    pub quality: MeshQuality,
+    pub material: SliceMaterial,
+}
+
+impl Default for ChartSpec {
+    fn default() -> Self {
+        Self {
+            position: Vec3::ZERO,
+            height: PIE_HEIGHT,
+            base_radius: BASE_RADIUS,
+            max_chord_error: 0.02,
+            max_segments: 64,
+            quality: MeshQuality::default(),
+            material: SliceMaterial::default(),
+        }
+    }
+}
+
+impl ChartSpec {
+    /// The same spec centered at `position`
+    pub fn at(&self, position: Vec3) -> Self {
+        Self { position, ..self.clone() }
+    }
+
+    /// Segments needed for an arc of `span` radians at `radius` to stay within the
+    /// chord error tolerance. A segment never covers more than a quarter turn.
+    pub fn segment_count(&self, span: f32, radius: f32) -> u32 {
+        if span <= 0.0 || radius <= 0.0 {
+            return 1;
+        }
+        let tolerance = self.max_chord_error * self.quality.chord_error_scale();
+        // Sagitta of a chord spanning `step` radians: radius * (1 - cos(step / 2))
+        let max_step = (2.0 * (1.0 - tolerance / radius).clamp(-1.0, 1.0).acos()).min(PI / 2.0);
+        ((span / max_step).ceil() as u32).clamp(1, self.max_segments.max(1))
+    }
+}
+
+/// Angular span and radius of one category's slice
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SliceLayout {
+    pub category_index: usize,
+    pub start_angle: f32,
+    pub end_angle: f32,
+    pub radius: f32,
+}
+
+impl SliceLayout {
+    /// Zero-width or zero-radius slices have no geometry
+    pub fn is_empty(&self) -> bool {
+        self.end_angle <= self.start_angle || self.radius <= 0.0
+    }
+}
+
+/// `value` as a fraction of `total`, or an even split of `count` when the total is zero
+fn share(value: f32, total: f32, count: usize) -> f32 {
+    if total > 0.0 { value / total } else { 1.0 / count.max(1) as f32 }
+}
+
+/// Fraction of an entity's chart covered by a category's slice.
+/// An entity whose values are all zero gets an even split.
+pub fn angular_share(data: &[CompetitionData], category: usize, entity: usize) -> f32 {
+    let total: f32 = data.iter().map(|d| d.values[entity]).sum();
+    share(data[category].values[entity], total, data.len())
+}
+
+/// An entity's share of the category total across all N entities, the input to
+/// the radius strategy. A category whose values are all zero counts as an even split.
+pub fn radial_share(data: &CompetitionData, entity: usize) -> f32 {
+    share(data.values[entity], data.total(), data.values.len())
+}
+
+/// Start and end angle of every category's slice on the given entity's chart
+pub fn slice_angles(data: &[CompetitionData], entity: usize) -> Vec<(f32, f32)> {
+    let total: f32 = data.iter().map(|d| d.values[entity]).sum();
+    let mut angle = 0.0;
+    data.iter()
+        .map(|d| {
+            let slice_angle = share(d.values[entity], total, data.len()) * 2.0 * PI;
+            let span = (angle, angle + slice_angle);
+            angle += slice_angle;
+            span
+        })
+        .collect()
+}
+
+/// Radius for an entity's slice in a category, from its radial share
+pub fn slice_radius(data: &CompetitionData, entity: usize, radius: &RadiusConfig, base_radius: f32) -> f32 {
+    radius.radius(radial_share(data, entity), data.values.len(), base_radius)
+}
+
+/// Every slice of the given entity's chart, in category order
+pub fn layout_chart(data: &[CompetitionData], entity: usize, radius: &RadiusConfig, spec: &ChartSpec) -> Vec<SliceLayout> {
+    data.iter()
+        .zip(slice_angles(data, entity))
+        .enumerate()
+        .map(|(category_index, (entry, (start_angle, end_angle)))| SliceLayout {
+            category_index,
+            start_angle,
+            end_angle,
+            radius: slice_radius(entry, entity, radius, spec.base_radius),
+        })
+        .collect()
+}
+
+/// Spawns one slice entity per category for the given entity's chart.
+/// Empty slices are still spawned so an edit can grow them in place.
+pub fn spawn_chart(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    game_data: &GameData,
+    spec: &ChartSpec,
+    entity_index: usize,
+) {
+    let colors = &game_data.colors;
+    for slice in layout_chart(&game_data.data, entity_index, &game_data.radius, spec) {
+        let color = colors[slice.category_index % colors.len()];
+        commands.spawn((
+            PbrBundle {
+                mesh: meshes.add(create_pie_slice_mesh(&slice, spec)),
+                material: materials.add(spec.material.standard_material(color)),
+                transform: Transform::from_translation(spec.position),
+                ..default()
+            },
+            PieSlice {
+                category_index: slice.category_index,
+                start_angle: slice.start_angle,
+                end_angle: slice.end_angle,
+                radius: slice.radius,
+                entity_index,
+            },
+        ));
+    }
+}
+
+/// Builds a watertight wedge in the XY plane, extruded along Z and centered on
+/// the chart origin. Every face has its own vertices: the front and back caps,
+/// the curved outer wall with radial normals and the two flat radial cuts.
+pub fn create_pie_slice_mesh(slice: &SliceLayout, spec: &ChartSpec) -> Mesh {
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+    let mut wedge = WedgeBuilder::default();
+    if !slice.is_empty() {
+        let segments = spec.segment_count(slice.end_angle - slice.start_angle, slice.radius);
+        wedge.build(slice, spec.height, segments);
+    }
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, wedge.positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, wedge.normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, wedge.uvs);
+    mesh.insert_indices(Indices::U32(wedge.indices));
+    mesh
+}
+
+#[derive(Default)]
+struct WedgeBuilder {
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    uvs: Vec<[f32; 2]>,
+    indices: Vec<u32>,
+}
+
+impl WedgeBuilder {
+    fn vertex(&mut self, position: [f32; 3], normal: [f32; 3], uv: [f32; 2]) -> u32 {
+        self.positions.push(position);
+        self.normals.push(normal);
+        self.uvs.push(uv);
+        self.positions.len() as u32 - 1
+    }
+
+    fn build(&mut self, slice: &SliceLayout, height: f32, segments: u32) {
+        let (radius, half) = (slice.radius, height / 2.0);
+        let step = (slice.end_angle - slice.start_angle) / segments as f32;
+        let arc: Vec<(f32, f32)> = (0..=segments)
+            .map(|i| {
+                let angle = slice.start_angle + i as f32 * step;
+                (angle.cos(), angle.sin())
+            })
+            .collect();
+
+        // Front (+Z) and back (-Z) caps, UVs mapped from a disc onto the unit square
+        for (z, normal_z) in [(half, 1.0), (-half, -1.0)] {
+            let center = self.vertex([0.0, 0.0, z], [0.0, 0.0, normal_z], [0.5, 0.5]);
+            let first = center + 1;
+            for &(cos, sin) in &arc {
+                self.vertex([radius * cos, radius * sin, z], [0.0, 0.0, normal_z], [0.5 + 0.5 * cos, 0.5 - 0.5 * sin]);
+            }
+            for i in 0..segments {
+                let (a, b) = (first + i, first + i + 1);
+                if normal_z > 0.0 {
+                    self.indices.extend_from_slice(&[center, a, b]);
+                } else {
+                    self.indices.extend_from_slice(&[center, b, a]);
+                }
+            }
+        }
+
+        // Curved outer wall; U runs along the arc, V from front to back
+        let first = self.positions.len() as u32;
+        for (i, &(cos, sin)) in arc.iter().enumerate() {
+            let u = i as f32 / segments as f32;
+            self.vertex([radius * cos, radius * sin, half], [cos, sin, 0.0], [u, 0.0]);
+            self.vertex([radius * cos, radius * sin, -half], [cos, sin, 0.0], [u, 1.0]);
+        }
+        for i in 0..segments {
+            let (front, back) = (first + 2 * i, first + 2 * i + 1);
+            let (next_front, next_back) = (front + 2, back + 2);
+            self.indices.extend_from_slice(&[front, back, next_front, back, next_back, next_front]);
+        }
+
+        // Radial cuts at both ends; U runs from the center outward
+        let (start_cos, start_sin) = arc[0];
+        let (end_cos, end_sin) = arc[arc.len() - 1];
+        for (cos, sin, normal, flip) in [
+            (start_cos, start_sin, [start_sin, -start_cos, 0.0], false),
+            (end_cos, end_sin, [-end_sin, end_cos, 0.0], true),
+        ] {
+            let center_front = self.vertex([0.0, 0.0, half], normal, [0.0, 0.0]);
+            let center_back = self.vertex([0.0, 0.0, -half], normal, [0.0, 1.0]);
+            let edge_front = self.vertex([radius * cos, radius * sin, half], normal, [1.0, 0.0]);
+            let edge_back = self.vertex([radius * cos, radius * sin, -half], normal, [1.0, 1.0]);
+            if flip {
+                self.indices.extend_from_slice(&[center_front, edge_front, center_back, center_back, edge_front, edge_back]);
+            } else {
+                self.indices.extend_from_slice(&[center_front, center_back, edge_front, center_back, edge_back, edge_front]);
+            }
+        }
+    }
+}
+
+/// `Q` cycles the global mesh quality and rebuilds the charts
+pub fn cycle_mesh_quality(
+    mut spec: ResMut<ChartSpec>,
+    mut rebuild: EventWriter<RebuildCharts>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    table: Res<DataTableState>,
+) {
+    if table.editing.is_none() && keyboard.just_pressed(KeyCode::KeyQ) {
+        spec.quality = spec.quality.next();
+        rebuild.send(RebuildCharts);
+        info!("Mesh quality: {:?}", spec.quality);
+    }
+}
@@ -0,0 +1,259 @@
+//! CSV import for Pi vs Pi datasets.
+// Spreadsheet exports are mapped onto `CompetitionData` by choosing which
+// column supplies the category name and which columns supply one value per
+// entity. When the file has a header row, the value column headers name the
+// entities.
+
+use super::radius::RadiusConfig;
+use super::{CompetitionData, Dataset, EntityDef};
+use super::dataset::{validate_dataset, DatasetError};
+
+/// Identifies a CSV column either by position or by header text
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColumnRef {
+    /// Zero-based column position
+    Index(usize),
+    /// Header cell text (matched case-insensitively, surrounding whitespace ignored)
+    Header(String),
+}
+
+impl ColumnRef {
+    /// Parse a command-line column selector: a 1-based number or a header name
+    pub fn parse(spec: &str) -> ColumnRef {
+        match spec.trim().parse::<usize>() {
+            Ok(n) if n > 0 => ColumnRef::Index(n - 1),
+            _ => ColumnRef::Header(spec.trim().to_string()),
+        }
+    }
+}
+
+/// Whether the first record of the file is a header row
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum HeaderMode {
+    /// Treat the first record as a header if its value columns are not numeric
+    #[default]
+    Auto,
+    Present,
+    Absent,
+}
+
+/// Which CSV columns feed each `CompetitionData` field
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvMapping {
+    pub name: ColumnRef,
+    /// One column per entity, in chart order
+    pub values: Vec<ColumnRef>,
+    pub header: HeaderMode,
+}
+
+impl Default for CsvMapping {
+    /// name, left, right in the first three columns with header auto-detection
+    fn default() -> Self {
+        Self {
+            name: ColumnRef::Index(0),
+            values: vec![ColumnRef::Index(1), ColumnRef::Index(2)],
+            header: HeaderMode::Auto,
+        }
+    }
+}
+
+/// A single CSV cell with its 1-based source position
+#[derive(Clone, Debug)]
+struct Field {
+    text: String,
+    line: usize,
+    column: usize,
+}
+
+/// Parse CSV text into categories using the given column mapping
+pub fn parse_csv_dataset(csv: &str, mapping: &CsvMapping) -> Result<Dataset, DatasetError> {
+    let records = split_records(csv)?;
+    let Some(first) = records.first() else {
+        return Err(DatasetError::Empty);
+    };
+
+    let has_header = match mapping.header {
+        HeaderMode::Present => true,
+        HeaderMode::Absent => false,
+        HeaderMode::Auto => looks_like_header(first, mapping),
+    };
+    let header = if has_header { Some(first) } else { None };
+
+    let name_col = resolve_column(&mapping.name, header, "name")?;
+    let value_cols = mapping
+        .values
+        .iter()
+        .enumerate()
+        .map(|(i, column)| resolve_column(column, header, &format!("value #{}", i + 1)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let body = if has_header { &records[1..] } else { &records[..] };
+    let mut data = Vec::with_capacity(body.len());
+    for record in body {
+        let values = value_cols
+            .iter()
+            .map(|&col| parse_value(field_at(record, col)?))
+            .collect::<Result<Vec<_>, _>>()?;
+        data.push(CompetitionData::new(field_at(record, name_col)?.text.trim(), values));
+    }
+
+    let entities = value_cols
+        .iter()
+        .enumerate()
+        .map(|(i, &col)| {
+            let name = header_text(header, col).unwrap_or_else(|| format!("Entity {}", i + 1));
+            EntityDef::new(name, EntityDef::default_color(i))
+        })
+        .collect();
+
+    let dataset = Dataset { entities, categories: data, radius: RadiusConfig::default() };
+    validate_dataset(&dataset)?;
+    Ok(dataset)
+}
+
+fn header_text(header: Option<&Vec<Field>>, index: usize) -> Option<String> {
+    let text = header?.get(index)?.text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// A header row is assumed when any mapped value cell fails to parse as a number.
+/// Missing cells are left for the row check to report.
+fn looks_like_header(first: &[Field], mapping: &CsvMapping) -> bool {
+    let is_number = |column: &ColumnRef| match column {
+        ColumnRef::Index(i) => first.get(*i).is_none_or(|f| f.text.trim().parse::<f32>().is_ok()),
+        ColumnRef::Header(_) => false,
+    };
+    !mapping.values.iter().all(is_number)
+}
+
+fn resolve_column(column: &ColumnRef, header: Option<&Vec<Field>>, role: &str) -> Result<usize, DatasetError> {
+    match column {
+        ColumnRef::Index(i) => Ok(*i),
+        ColumnRef::Header(wanted) => {
+            let Some(header) = header else {
+                return Err(DatasetError::Csv {
+                    line: 1,
+                    column: 1,
+                    message: format!("{} column {:?} selected by header, but the file has no header row", role, wanted),
+                });
+            };
+            header
+                .iter()
+                .position(|f| f.text.trim().eq_ignore_ascii_case(wanted.trim()))
+                .ok_or_else(|| DatasetError::Csv {
+                    line: header.first().map_or(1, |f| f.line),
+                    column: 1,
+                    message: format!("no header named {:?} for the {} column", wanted, role),
+                })
+        }
+    }
+}
+
+fn field_at(record: &[Field], index: usize) -> Result<&Field, DatasetError> {
+    record.get(index).ok_or_else(|| {
+        let last = record.last().expect("records are never empty");
+        DatasetError::Csv {
+            line: last.line,
+            column: index + 1,
+            message: format!("row has {} columns, expected at least {}", record.len(), index + 1),
+        }
+    })
+}
+
+fn parse_value(field: &Field) -> Result<f32, DatasetError> {
+    field.text.trim().parse::<f32>().map_err(|_| DatasetError::Csv {
+        line: field.line,
+        column: field.column,
+        message: format!("expected a number, found {:?}", field.text),
+    })
+}
+
+/// Split RFC 4180 style CSV into records, honouring quoted fields.
+/// Blank lines are skipped.
+fn split_records(csv: &str) -> Result<Vec<Vec<Field>>, DatasetError> {
+    let mut records = Vec::new();
+    let mut record: Vec<Field> = Vec::new();
+    let mut text = String::new();
+    let mut line = 1;
+    let mut field_line = 1;
+    let mut in_quotes = false;
+    let mut quoted = false;
+    let mut chars = csv.chars().peekable();
+
+    let finish_field = |record: &mut Vec<Field>, text: &mut String, field_line: usize| {
+        let column = record.len() + 1;
+        record.push(Field { text: std::mem::take(text), line: field_line, column });
+    };
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    text.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    text.push('\n');
+                }
+                _ => text.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if text.trim().is_empty() && !quoted => {
+                text.clear();
+                in_quotes = true;
+                quoted = true;
+            }
+            '"' => {
+                return Err(DatasetError::Csv {
+                    line,
+                    column: record.len() + 1,
+                    message: "unexpected quote inside an unquoted field".to_string(),
+                });
+            }
+            ',' => {
+                finish_field(&mut record, &mut text, field_line);
+                quoted = false;
+                field_line = line;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                if !record.is_empty() || !text.trim().is_empty() || quoted {
+                    finish_field(&mut record, &mut text, field_line);
+                    records.push(std::mem::take(&mut record));
+                }
+                text.clear();
+                quoted = false;
+                line += 1;
+                field_line = line;
+            }
+            _ if quoted => {
+                if !c.is_whitespace() {
+                    return Err(DatasetError::Csv {
+                        line,
+                        column: record.len() + 1,
+                        message: "unexpected text after closing quote".to_string(),
+                    });
+                }
+            }
+            _ => text.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(DatasetError::Csv {
+            line: field_line,
+            column: record.len() + 1,
+            message: "unterminated quoted field".to_string(),
+        });
+    }
+    if !record.is_empty() || !text.trim().is_empty() || quoted {
+        finish_field(&mut record, &mut text, field_line);
+        records.push(record);
+    }
+    Ok(records)
+}
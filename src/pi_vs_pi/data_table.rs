@@ -0,0 +1,396 @@
+//! In-app data table mirroring the web version's footer table.
+// Lists every category with one value column per entity. Clicking a row
+// selects the category; clicking a value cell edits it in place and the
+// affected slices are reshaped as soon as the edit is committed.
+
+use bevy::input::keyboard::KeyCode;
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+use bevy::window::ReceivedCharacter;
+
+use super::dataset::is_valid_value;
+use super::{select_category, ChartRotation, GameData, RebuildCharts, SliceValueChanged};
+
+const PANEL_COLOR: Color = Color::rgba(0.05, 0.05, 0.05, 0.92);
+const ROW_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.0);
+const ROW_HOVER_COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.06);
+const ROW_SELECTED_COLOR: Color = Color::rgba(0.30, 0.69, 0.31, 0.25);
+const CELL_EDIT_COLOR: Color = Color::rgba(1.0, 1.0, 0.33, 0.2);
+const TEXT_COLOR: Color = Color::rgb(0.85, 0.85, 0.85);
+const ERROR_COLOR: Color = Color::rgb(1.0, 0.35, 0.35);
+const FONT_SIZE: f32 = 16.0;
+const NAME_COLUMN_WIDTH: f32 = 180.0;
+const VALUE_COLUMN_WIDTH: f32 = 110.0;
+
+/// Expanded/collapsed state and the cell currently being edited
+#[derive(Resource, Default)]
+pub struct DataTableState {
+    pub expanded: bool,
+    pub editing: Option<CellEdit>,
+    pub status: Option<String>,
+}
+
+/// Text typed into a value cell that has not been committed yet
+#[derive(Clone, Debug)]
+pub struct CellEdit {
+    pub category_index: usize,
+    pub entity_index: usize,
+    pub buffer: String,
+}
+
+#[derive(Component)]
+pub struct DataTableToggle;
+
+#[derive(Component)]
+pub struct DataTableBody;
+
+#[derive(Component)]
+pub struct DataTableStatus;
+
+#[derive(Component)]
+pub struct TableRow {
+    pub category_index: usize,
+}
+
+#[derive(Component)]
+pub struct ValueCell {
+    pub category_index: usize,
+    pub entity_index: usize,
+}
+
+fn text_style(color: Color) -> TextStyle {
+    TextStyle {
+        font_size: FONT_SIZE,
+        color,
+        ..default()
+    }
+}
+
+fn cell_style(width: f32) -> Style {
+    Style {
+        width: Val::Px(width),
+        padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+        ..default()
+    }
+}
+
+/// Spawns the footer panel (collapsed by default) with one row per category
+pub fn spawn_data_table(mut commands: Commands, game_data: Res<GameData>, state: Res<DataTableState>) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(0.0),
+                left: Val::Px(0.0),
+                right: Val::Px(0.0),
+                flex_direction: FlexDirection::Column,
+                max_height: Val::Percent(45.0),
+                ..default()
+            },
+            background_color: PANEL_COLOR.into(),
+            ..default()
+        })
+        .with_children(|panel| {
+            panel
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            padding: UiRect::all(Val::Px(8.0)),
+                            ..default()
+                        },
+                        background_color: Color::rgba(1.0, 1.0, 1.0, 0.08).into(),
+                        ..default()
+                    },
+                    DataTableToggle,
+                ))
+                .with_children(|toggle| {
+                    toggle.spawn(TextBundle::from_section(
+                        toggle_label(state.expanded),
+                        text_style(Color::rgb(1.0, 1.0, 0.33)),
+                    ));
+                });
+
+            panel.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        overflow: Overflow::clip_y(),
+                        display: if state.expanded { Display::Flex } else { Display::None },
+                        ..default()
+                    },
+                    ..default()
+                },
+                DataTableBody,
+            ))
+            .with_children(|body| spawn_rows(body, &game_data));
+
+            panel.spawn((
+                TextBundle::from_section("", text_style(ERROR_COLOR)).with_style(Style {
+                    padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                    ..default()
+                }),
+                DataTableStatus,
+            ));
+        });
+}
+
+fn toggle_label(expanded: bool) -> String {
+    format!("{} DATA TABLE (T)", if expanded { "▼" } else { "▲" })
+}
+
+fn spawn_rows(body: &mut ChildBuilder, game_data: &GameData) {
+    // Column headings
+    body.spawn(NodeBundle::default()).with_children(|row| {
+        row.spawn(TextBundle::from_section("CATEGORY", text_style(Color::rgb(1.0, 1.0, 0.33)))
+            .with_style(cell_style(NAME_COLUMN_WIDTH)));
+        for entity in &game_data.entities {
+            row.spawn(TextBundle::from_section(entity.name.to_uppercase(), text_style(entity.color))
+                .with_style(cell_style(VALUE_COLUMN_WIDTH)));
+        }
+    });
+
+    for (category_index, entry) in game_data.data.iter().enumerate() {
+        body.spawn((
+            ButtonBundle {
+                background_color: ROW_COLOR.into(),
+                ..default()
+            },
+            TableRow { category_index },
+        ))
+        .with_children(|row| {
+            let swatch = game_data.colors[category_index % game_data.colors.len()];
+            row.spawn(TextBundle::from_section(format!("■ {}", entry.name), text_style(swatch))
+                .with_style(cell_style(NAME_COLUMN_WIDTH)));
+            for (entity_index, value) in entry.values.iter().enumerate() {
+                row.spawn((
+                    ButtonBundle {
+                        style: cell_style(VALUE_COLUMN_WIDTH),
+                        background_color: ROW_COLOR.into(),
+                        ..default()
+                    },
+                    ValueCell { category_index, entity_index },
+                ))
+                .with_children(|cell| {
+                    cell.spawn(TextBundle::from_section(value.to_string(), text_style(TEXT_COLOR)));
+                });
+            }
+        });
+    }
+}
+
+/// Repopulates the rows after the dataset is replaced
+pub fn rebuild_table_rows(
+    mut commands: Commands,
+    mut events: EventReader<RebuildCharts>,
+    mut state: ResMut<DataTableState>,
+    game_data: Res<GameData>,
+    body: Query<Entity, With<DataTableBody>>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+    state.editing = None;
+    for entity in body.iter() {
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|body| spawn_rows(body, &game_data));
+    }
+}
+
+pub fn handle_table_clicks(
+    mut state: ResMut<DataTableState>,
+    mut game_data: ResMut<GameData>,
+    mut rotation: ResMut<ChartRotation>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    toggles: Query<&Interaction, (Changed<Interaction>, With<DataTableToggle>)>,
+    rows: Query<(&Interaction, &TableRow), Changed<Interaction>>,
+    cells: Query<(&Interaction, &ValueCell), Changed<Interaction>>,
+) {
+    let toggle_key = state.editing.is_none() && keyboard.just_pressed(KeyCode::KeyT);
+    if toggle_key || toggles.iter().any(|i| *i == Interaction::Pressed) {
+        state.expanded = !state.expanded;
+    }
+
+    for (interaction, row) in rows.iter() {
+        // Rows outlive a reload that drops categories until they are respawned
+        if *interaction == Interaction::Pressed && row.category_index < game_data.data.len() {
+            select_category(&mut game_data, &mut rotation, row.category_index);
+        }
+    }
+
+    for (interaction, cell) in cells.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(&value) = cell_value(&game_data, cell.category_index, cell.entity_index) else {
+            continue;
+        };
+        state.editing = Some(CellEdit {
+            category_index: cell.category_index,
+            entity_index: cell.entity_index,
+            buffer: value.to_string(),
+        });
+        state.status = None;
+        select_category(&mut game_data, &mut rotation, cell.category_index);
+    }
+}
+
+/// The value shown in a cell, or `None` for a cell left over from before a
+/// reload that dropped its category or entity
+fn cell_value(game_data: &GameData, category_index: usize, entity_index: usize) -> Option<&f32> {
+    game_data.data.get(category_index)?.values.get(entity_index)
+}
+
+/// Routes typed characters into the cell being edited; Enter commits, Escape cancels
+pub fn handle_cell_typing(
+    mut state: ResMut<DataTableState>,
+    mut game_data: ResMut<GameData>,
+    mut rotation: ResMut<ChartRotation>,
+    mut characters: EventReader<ReceivedCharacter>,
+    mut changed: EventWriter<SliceValueChanged>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+) {
+    // Checked before borrowing mutably so an idle table is not marked changed
+    if state.editing.is_none() {
+        characters.clear();
+        return;
+    }
+    let Some(edit) = state.editing.as_mut() else {
+        return;
+    };
+
+    for event in characters.read() {
+        for c in event.char.chars() {
+            if c.is_ascii_digit() || c == '.' || c == '-' {
+                edit.buffer.push(c);
+            }
+        }
+    }
+    if keyboard.just_pressed(KeyCode::Backspace) {
+        edit.buffer.pop();
+    }
+
+    if keyboard.just_pressed(KeyCode::Escape) {
+        state.editing = None;
+        state.status = None;
+        return;
+    }
+    if !keyboard.just_pressed(KeyCode::Enter) {
+        return;
+    }
+
+    let (category_index, entity_index) = (edit.category_index, edit.entity_index);
+    if cell_value(&game_data, category_index, entity_index).is_none() {
+        state.editing = None;
+        return;
+    }
+    match edit.buffer.trim().parse::<f32>() {
+        Ok(value) if is_valid_value(value) => {
+            game_data.data[category_index].values[entity_index] = value;
+            changed.send(SliceValueChanged { category_index, entity_index });
+            // Slice spans moved, so re-center the driven chart on the same category
+            let selected = game_data.selected_category;
+            select_category(&mut game_data, &mut rotation, selected);
+            state.editing = None;
+            state.status = None;
+        }
+        _ => {
+            state.status = Some(format!(
+                "Invalid value {:?}: enter a finite number of 0 or more (Esc to cancel)",
+                edit.buffer
+            ));
+        }
+    }
+}
+
+/// Keeps toggle label, visibility, highlights and cell text in sync with state.
+/// Only row hover is followed every frame; the rest is redrawn when the data
+/// or table state changes.
+#[allow(clippy::too_many_arguments)]
+pub fn refresh_table_view(
+    state: Res<DataTableState>,
+    game_data: Res<GameData>,
+    toggles: Query<&Children, With<DataTableToggle>>,
+    mut bodies: Query<&mut Style, With<DataTableBody>>,
+    mut rows: Query<(&TableRow, Ref<Interaction>, &mut BackgroundColor), Without<ValueCell>>,
+    mut cells: Query<(&ValueCell, &Children, &mut BackgroundColor), Without<TableRow>>,
+    mut status: Query<&mut Text, With<DataTableStatus>>,
+    mut texts: Query<&mut Text, Without<DataTableStatus>>,
+) {
+    let changed = state.is_changed() || game_data.is_changed();
+    for (row, interaction, mut background) in rows.iter_mut() {
+        if !changed && !interaction.is_changed() {
+            continue;
+        }
+        let color = if row.category_index == game_data.selected_category {
+            ROW_SELECTED_COLOR
+        } else if *interaction == Interaction::Hovered {
+            ROW_HOVER_COLOR
+        } else {
+            ROW_COLOR
+        };
+        set_background(&mut background, color);
+    }
+    if !changed {
+        return;
+    }
+
+    for children in toggles.iter() {
+        for &child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                set_text(&mut text, toggle_label(state.expanded), None);
+            }
+        }
+    }
+    for mut style in bodies.iter_mut() {
+        let display = if state.expanded { Display::Flex } else { Display::None };
+        if style.display != display {
+            style.display = display;
+        }
+    }
+
+    for (cell, children, mut background) in cells.iter_mut() {
+        let Some(value) = cell_value(&game_data, cell.category_index, cell.entity_index) else {
+            continue;
+        };
+        let edit = state.editing.as_ref().filter(|e| {
+            e.category_index == cell.category_index && e.entity_index == cell.entity_index
+        });
+        let (label, color) = match edit {
+            Some(edit) => {
+                let color = if state.status.is_some() { ERROR_COLOR } else { TEXT_COLOR };
+                (format!("{}_", edit.buffer), color)
+            }
+            None => (value.to_string(), TEXT_COLOR),
+        };
+        set_background(&mut background, if edit.is_some() { CELL_EDIT_COLOR } else { ROW_COLOR });
+        for &child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                set_text(&mut text, label.clone(), Some(color));
+            }
+        }
+    }
+
+    for mut text in status.iter_mut() {
+        set_text(&mut text, state.status.clone().unwrap_or_default(), None);
+    }
+}
+
+fn set_background(background: &mut Mut<BackgroundColor>, color: Color) {
+    if background.0 != color {
+        background.0 = color;
+    }
+}
+
+/// Only touch `Text` when the content differs so unchanged cells are not re-laid out
+fn set_text(text: &mut Mut<Text>, value: String, color: Option<Color>) {
+    let section = &text.sections[0];
+    if section.value != value || color.is_some_and(|c| c != section.style.color) {
+        let section = &mut text.sections[0];
+        section.value = value;
+        if let Some(color) = color {
+            section.style.color = color;
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Dataset loading for Pi vs Pi.
+// Reads a `Dataset` JSON object, a legacy JSON array of categories
+// (README Appendix A) or a CSV export from disk so matchups can be swapped
+// without recompiling.
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::radius::RadiusConfig;
+use super::{CompetitionData, Dataset, EntityDef, MAX_ENTITIES, MIN_ENTITIES};
+use super::csv_import::{parse_csv_dataset, CsvMapping};
+
+/// Errors produced while loading a dataset file
+#[derive(Debug)]
+pub enum DatasetError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file is not a valid JSON dataset
+    Json(serde_json::Error),
+    /// The CSV file is malformed or a cell does not match the column mapping
+    Csv {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The file parsed but contains no categories
+    Empty,
+    /// The number of entities is outside `MIN_ENTITIES..=MAX_ENTITIES`
+    EntityCount(usize),
+    /// A category does not have exactly one value per entity
+    ValueCount {
+        index: usize,
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    /// A category value is negative, NaN or infinite
+    InvalidValue {
+        index: usize,
+        name: String,
+        entity: String,
+        value: f32,
+    },
+}
+
+impl fmt::Display for DatasetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DatasetError::Io(err) => write!(f, "could not read dataset: {}", err),
+            DatasetError::Json(err) => write!(f, "malformed dataset JSON: {}", err),
+            DatasetError::Csv { line, column, message } => {
+                write!(f, "malformed dataset CSV at line {}, column {}: {}", line, column, message)
+            }
+            DatasetError::Empty => write!(f, "dataset contains no categories"),
+            DatasetError::EntityCount(count) => write!(
+                f,
+                "dataset defines {} entities, expected between {} and {}",
+                count, MIN_ENTITIES, MAX_ENTITIES
+            ),
+            DatasetError::ValueCount { index, name, expected, found } => write!(
+                f,
+                "category #{} ({:?}) has {} values, expected one per entity ({})",
+                index + 1,
+                name,
+                found,
+                expected
+            ),
+            DatasetError::InvalidValue { index, name, entity, value } => write!(
+                f,
+                "category #{} ({:?}) has invalid {} value {}: values must be finite and non-negative",
+                index + 1,
+                name,
+                entity,
+                value
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DatasetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DatasetError::Io(err) => Some(err),
+            DatasetError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for DatasetError {
+    fn from(err: std::io::Error) -> Self {
+        DatasetError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for DatasetError {
+    fn from(err: serde_json::Error) -> Self {
+        DatasetError::Json(err)
+    }
+}
+
+/// Load a dataset from disk. Files ending in `.csv` are read with the given
+/// column mapping, everything else is parsed as JSON.
+pub fn load_dataset(path: &Path, csv_mapping: &CsvMapping) -> Result<Dataset, DatasetError> {
+    let contents = fs::read_to_string(path)?;
+    let is_csv = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+    if is_csv {
+        parse_csv_dataset(&contents, csv_mapping)
+    } else {
+        parse_json_dataset(&contents)
+    }
+}
+
+/// Parse and validate a JSON dataset. A top-level array is the legacy
+/// category list and gets the Kobold vs Troglodyte entities.
+pub fn parse_json_dataset(json: &str) -> Result<Dataset, DatasetError> {
+    let dataset = if json.trim_start().starts_with('[') {
+        Dataset {
+            entities: EntityDef::legacy_pair(),
+            radius: RadiusConfig::default(),
+            categories: serde_json::from_str::<Vec<CompetitionData>>(json)?,
+        }
+    } else {
+        serde_json::from_str::<Dataset>(json)?
+    };
+    validate_dataset(&dataset)?;
+    Ok(dataset)
+}
+
+/// Reject datasets the chart builder cannot draw
+pub fn validate_dataset(dataset: &Dataset) -> Result<(), DatasetError> {
+    if dataset.categories.is_empty() {
+        return Err(DatasetError::Empty);
+    }
+    let count = dataset.entities.len();
+    if !(MIN_ENTITIES..=MAX_ENTITIES).contains(&count) {
+        return Err(DatasetError::EntityCount(count));
+    }
+    for (index, entry) in dataset.categories.iter().enumerate() {
+        if entry.values.len() != count {
+            return Err(DatasetError::ValueCount {
+                index,
+                name: entry.name.clone(),
+                expected: count,
+                found: entry.values.len(),
+            });
+        }
+        for (entity, &value) in dataset.entities.iter().zip(&entry.values) {
+            if !is_valid_value(value) {
+                return Err(DatasetError::InvalidValue {
+                    index,
+                    name: entry.name.clone(),
+                    entity: entity.name.clone(),
+                    value,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Category values must be finite and non-negative to be drawn as slices
+pub fn is_valid_value(value: f32) -> bool {
+    value.is_finite() && value >= 0.0
+}
+
+/// On-disk shape of a category: either a `values` list or a `left`/`right`
+/// pair (also accepted under the legacy `kobold`/`troglodyte` keys)
+#[derive(Deserialize)]
+pub struct RawCompetitionData {
+    name: String,
+    #[serde(default)]
+    values: Option<Vec<f32>>,
+    #[serde(default, alias = "kobold")]
+    left: Option<f32>,
+    #[serde(default, alias = "troglodyte")]
+    right: Option<f32>,
+}
+
+impl TryFrom<RawCompetitionData> for CompetitionData {
+    type Error = String;
+
+    fn try_from(raw: RawCompetitionData) -> Result<Self, Self::Error> {
+        match (raw.values, raw.left, raw.right) {
+            (Some(values), None, None) => Ok(CompetitionData::new(raw.name, values)),
+            (None, Some(left), Some(right)) => Ok(CompetitionData::new(raw.name, vec![left, right])),
+            _ => Err(format!(
+                "category {:?} needs either `values` or both `left` and `right`",
+                raw.name
+            )),
+        }
+    }
+}
+
+/// Serde helper storing a `Color` as a `#rrggbb` hex string
+pub mod hex_color {
+    use bevy::prelude::Color;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        let [r, g, b, _] = color.as_rgba_u8();
+        serializer.serialize_str(&format!("#{:02x}{:02x}{:02x}", r, g, b))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        Color::hex(&hex).map_err(|err| D::Error::custom(format!("invalid color {:?}: {}", hex, err)))
+    }
+}
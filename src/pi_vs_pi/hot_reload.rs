@@ -0,0 +1,137 @@
+//! Live reload of the dataset file.
+// Polls the loaded file's modification time and swaps the new categories
+// into `GameData` in place. Parse errors keep the previous data on screen and
+// show a banner instead of stopping the app.
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use bevy::prelude::*;
+
+use super::csv_import::CsvMapping;
+use super::{dataset, select_category, ChartRotation, Dataset, GameData, RebuildCharts};
+
+/// How often the dataset file's modification time is checked
+const POLL_INTERVAL_SECS: f32 = 0.5;
+
+/// The file the running app was launched with
+#[derive(Resource)]
+pub struct DatasetSource {
+    pub path: PathBuf,
+    pub csv_mapping: CsvMapping,
+    last_modified: Option<SystemTime>,
+    poll_timer: Timer,
+}
+
+impl DatasetSource {
+    pub fn new(path: PathBuf, csv_mapping: CsvMapping) -> Self {
+        let last_modified = modified_time(&path);
+        Self {
+            path,
+            csv_mapping,
+            last_modified,
+            poll_timer: Timer::from_seconds(POLL_INTERVAL_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+/// On-screen banner describing the last failed reload
+#[derive(Component)]
+pub struct ErrorBanner;
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+pub fn watch_dataset_file(
+    mut commands: Commands,
+    source: Option<ResMut<DatasetSource>>,
+    mut game_data: ResMut<GameData>,
+    mut rotation: ResMut<ChartRotation>,
+    mut rebuild: EventWriter<RebuildCharts>,
+    banners: Query<Entity, With<ErrorBanner>>,
+    time: Res<Time>,
+) {
+    let Some(mut source) = source else {
+        return;
+    };
+    if !source.poll_timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    // A missing file is usually an editor mid-save; wait for it to come back
+    let Some(modified) = modified_time(&source.path) else {
+        return;
+    };
+    if source.last_modified == Some(modified) {
+        return;
+    }
+    source.last_modified = Some(modified);
+
+    for entity in banners.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    match dataset::load_dataset(&source.path, &source.csv_mapping) {
+        Ok(dataset) => {
+            apply_dataset(&mut game_data, &mut rotation, dataset);
+            rebuild.send(RebuildCharts);
+            info!("Reloaded dataset {}", source.path.display());
+        }
+        Err(err) => {
+            let message = format!("Failed to reload {}: {}", source.path.display(), err);
+            error!("{}", message);
+            spawn_error_banner(&mut commands, message);
+        }
+    }
+}
+
+/// Swap in a reloaded dataset, keeping the selected category when its name still exists
+pub fn apply_dataset(game_data: &mut GameData, rotation: &mut ChartRotation, dataset: Dataset) {
+    let selected_name = game_data.data[game_data.selected_category].name.clone();
+    *game_data = GameData {
+        colors: std::mem::take(&mut game_data.colors),
+        ..GameData::new(dataset)
+    };
+    // Charts that still exist ease from where they were
+    let previous = std::mem::replace(rotation, ChartRotation::new(game_data.entity_count()));
+    for (angle, previous) in rotation.angles.iter_mut().zip(previous.angles) {
+        *angle = previous;
+    }
+
+    let selected = game_data
+        .data
+        .iter()
+        .position(|d| d.name == selected_name)
+        .unwrap_or(0);
+    select_category(game_data, rotation, selected);
+}
+
+fn spawn_error_banner(commands: &mut Commands, message: String) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(0.0),
+                    left: Val::Px(0.0),
+                    right: Val::Px(0.0),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                },
+                background_color: Color::rgba(0.6, 0.05, 0.05, 0.9).into(),
+                ..default()
+            },
+            ErrorBanner,
+        ))
+        .with_children(|banner| {
+            banner.spawn(TextBundle::from_section(
+                message,
+                TextStyle {
+                    font_size: 18.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ));
+        });
+}
@@ -0,0 +1,117 @@
+//! Mouse picking of pie slices.
+// The cursor ray is intersected with each chart's front face and the hit is
+// rotated back into the chart's unrotated frame, where a slice is just an
+// angular span out to its radius. Hovered slices glow; a click (a press and
+// release without dragging) selects the slice's category on every chart.
+
+use std::f32::consts::PI;
+
+use bevy::input::ButtonInput;
+use bevy::math::primitives::Plane3d;
+use bevy::math::Ray3d;
+use bevy::prelude::*;
+
+use super::chart::ChartSpec;
+use super::{select_category, ChartRotation, GameData, PieSlice};
+
+/// Cursor travel in pixels beyond which a press counts as a drag, not a click
+const CLICK_TOLERANCE: f32 = 5.0;
+/// Fraction of the slice color added as emissive light while hovered
+const HIGHLIGHT_STRENGTH: f32 = 0.45;
+
+/// The slice under the cursor and where the current mouse press started
+#[derive(Resource, Default)]
+pub struct SlicePicker {
+    pub hovered: Option<Entity>,
+    press_position: Option<Vec2>,
+}
+
+/// Where `ray` meets the front face of a chart placed by `chart`, in the chart's
+/// unrotated frame. The front face sits `half_height` in front of the chart center.
+pub fn chart_local_hit(ray: Ray3d, chart: &Transform, half_height: f32) -> Option<(Vec2, f32)> {
+    let normal = chart.rotation * Vec3::Z;
+    let face_center = chart.translation + normal * half_height;
+    let distance = ray.intersect_plane(face_center, Plane3d::new(normal))?;
+    let local = chart.rotation.inverse() * (ray.get_point(distance) - chart.translation);
+    Some((local.truncate(), distance))
+}
+
+/// Whether a point in a chart's unrotated frame lies inside a slice
+pub fn wedge_contains(start_angle: f32, end_angle: f32, radius: f32, point: Vec2) -> bool {
+    if end_angle <= start_angle || point.length() > radius {
+        return false;
+    }
+    let angle = point.y.atan2(point.x).rem_euclid(2.0 * PI);
+    angle >= start_angle && angle < end_angle
+}
+
+/// Tracks the hovered slice, highlights it and selects its category on click
+#[allow(clippy::too_many_arguments)]
+pub(super) fn pick_slices(
+    mut picker: ResMut<SlicePicker>,
+    mut game_data: ResMut<GameData>,
+    mut rotation: ResMut<ChartRotation>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    chart_spec: Res<ChartSpec>,
+    windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    slices: Query<(Entity, &PieSlice, &Transform, &Handle<StandardMaterial>)>,
+    ui: Query<&Interaction>,
+) {
+    let window = windows.single();
+    let cursor = window.cursor_position();
+    // UI panels sit on top of the charts
+    let over_ui = ui.iter().any(|interaction| *interaction != Interaction::None);
+
+    let ray = cursor
+        .filter(|_| !over_ui)
+        .and_then(|cursor| {
+            let (camera, camera_transform) = cameras.iter().find(|(camera, _)| camera.is_active)?;
+            camera.viewport_to_world(camera_transform, cursor)
+        });
+    let hovered = ray.and_then(|ray| {
+        slices
+            .iter()
+            .filter_map(|(entity, slice, transform, _)| {
+                let (point, distance) = chart_local_hit(ray, transform, chart_spec.height / 2.0)?;
+                wedge_contains(slice.start_angle, slice.end_angle, slice.radius, point).then_some((entity, distance))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(entity, _)| entity)
+    });
+
+    if hovered != picker.hovered {
+        for (entity, emissive) in [(picker.hovered, false), (hovered, true)] {
+            let Some((_, _, _, material)) = entity.and_then(|e| slices.get(e).ok()) else {
+                continue;
+            };
+            if let Some(material) = materials.get_mut(material) {
+                material.emissive = if emissive { highlight(material.base_color) } else { Color::BLACK };
+            }
+        }
+        picker.hovered = hovered;
+    }
+
+    if mouse_button.just_pressed(MouseButton::Left) {
+        picker.press_position = cursor;
+    }
+    if mouse_button.just_released(MouseButton::Left) {
+        let pressed_at = picker.press_position.take();
+        let is_click = pressed_at
+            .zip(cursor)
+            .is_some_and(|(pressed, released)| pressed.distance(released) <= CLICK_TOLERANCE);
+        let clicked = hovered.filter(|_| is_click).and_then(|e| slices.get(e).ok());
+        if let Some((_, slice, _, _)) = clicked.filter(|(_, slice, _, _)| slice.is_current(&game_data)) {
+            select_category(&mut game_data, &mut rotation, slice.category_index);
+        }
+    }
+}
+
+fn highlight(color: Color) -> Color {
+    Color::rgb(
+        color.r() * HIGHLIGHT_STRENGTH,
+        color.g() * HIGHLIGHT_STRENGTH,
+        color.b() * HIGHLIGHT_STRENGTH,
+    )
+}
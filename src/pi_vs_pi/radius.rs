@@ -0,0 +1,116 @@
+//! Radius strategies for the variable-radius algorithm.
+// A slice's radius encodes the entity's share of the category total. Linear
+// scaling exaggerates differences because slice area grows with the square of
+// the radius, so the mapping from share to radius is configurable per dataset
+// and can be cycled at runtime.
+
+use bevy::input::keyboard::KeyCode;
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
+
+use super::data_table::DataTableState;
+use super::{GameData, RebuildCharts};
+
+/// How an entity's share of a category total maps to a slice radius
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RadiusStrategy {
+    /// `base_radius * share` (the original algorithm)
+    #[default]
+    Linear,
+    /// `base_radius * sqrt(share)`, so slice area is proportional to share
+    AreaPreserving,
+    /// `base_radius * log10(1 + 9 * share)`, compressing large differences
+    Logarithmic,
+    /// Every slice gets the radius of an even split (a classic pie)
+    Fixed,
+}
+
+impl RadiusStrategy {
+    const ALL: [RadiusStrategy; 4] = [
+        RadiusStrategy::Linear,
+        RadiusStrategy::AreaPreserving,
+        RadiusStrategy::Logarithmic,
+        RadiusStrategy::Fixed,
+    ];
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|s| *s == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Radius as a fraction of the base radius for a share in `0..=1`
+    fn scale(self, share: f32, entity_count: usize) -> f32 {
+        match self {
+            RadiusStrategy::Linear => share,
+            RadiusStrategy::AreaPreserving => share.sqrt(),
+            RadiusStrategy::Logarithmic => (1.0 + 9.0 * share).log10(),
+            RadiusStrategy::Fixed => 1.0 / entity_count as f32,
+        }
+    }
+}
+
+/// Radius strategy plus an optional minimum radius floor
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+#[serde(default)]
+pub struct RadiusConfig {
+    pub strategy: RadiusStrategy,
+    /// Smallest allowed radius as a fraction of the base radius (0 disables the floor)
+    #[serde(deserialize_with = "fraction")]
+    pub min_fraction: f32,
+}
+
+/// Reads a `min_fraction`, rejecting values outside `0..=1`
+fn fraction<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+    let value = f32::deserialize(deserializer)?;
+    if (0.0..=1.0).contains(&value) {
+        Ok(value)
+    } else {
+        Err(D::Error::custom(format!("min_fraction must be between 0 and 1, found {}", value)))
+    }
+}
+
+/// Floor used when the floor is toggled on from the keyboard
+const DEFAULT_FLOOR: f32 = 0.15;
+
+impl RadiusConfig {
+    /// A config with `min_fraction` clamped to `0..=1`, so the floor can
+    /// neither go negative nor exceed the base radius
+    pub fn new(strategy: RadiusStrategy, min_fraction: f32) -> Self {
+        let min_fraction = if min_fraction.is_nan() { 0.0 } else { min_fraction.clamp(0.0, 1.0) };
+        Self { strategy, min_fraction }
+    }
+
+    /// Radius for an entity holding `share` of a category split between `entity_count` entities
+    pub fn radius(&self, share: f32, entity_count: usize, base_radius: f32) -> f32 {
+        let fraction = self.strategy.scale(share.clamp(0.0, 1.0), entity_count);
+        base_radius * fraction.max(self.min_fraction)
+    }
+}
+
+/// `R` cycles the strategy and `F` toggles the minimum radius floor
+pub fn toggle_radius_strategy(
+    mut game_data: ResMut<GameData>,
+    mut rebuild: EventWriter<RebuildCharts>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    table: Res<DataTableState>,
+) {
+    if table.editing.is_some() {
+        return;
+    }
+
+    let mut radius = game_data.radius;
+    if keyboard.just_pressed(KeyCode::KeyR) {
+        radius.strategy = radius.strategy.next();
+    }
+    if keyboard.just_pressed(KeyCode::KeyF) {
+        radius.min_fraction = if radius.min_fraction > 0.0 { 0.0 } else { DEFAULT_FLOOR };
+    }
+
+    if radius != game_data.radius {
+        game_data.radius = radius;
+        rebuild.send(RebuildCharts);
+        info!("Radius strategy: {:?}, floor: {}", radius.strategy, radius.min_fraction);
+    }
+}
@@ -0,0 +1,521 @@
+//! 3D stadium scoreboards.
+// Mirrors the web version's `createStadiumWall` and `createCubeScoreboard`: a
+// dark wall with a frame, separators and pillars behind one panel per entity
+// and a center panel for the selected category. Panels persist between
+// selections: each title and number line is a child `TinyText3d` entity that
+// remembers what it shows, and only a line whose text or color changed is
+// touched, which has `TinyTextPlugin` rebuild its cubes. Numbers are drawn as
+// glowing LEDs over unlit off pixels. In presentation mode numbers count up
+// to their new values instead of jumping; the digits are still only rebuilt
+// when the rounded text changes.
+
+use bevy::prelude::*;
+
+use crate::tiny_text::{TextAlign, TextMaterial, TextMeshMode, TinyText3d, TinyText3dBundle, VerticalAlign};
+
+use super::{CompetitionData, EntityDef, GameData, RebuildCharts};
+
+/// Height of the scoreboard row's center
+const BOARD_Y: f32 = 16.0;
+/// Room the boards are spread over, shared with the chart layout
+const BOARD_ROW_WIDTH: f32 = 25.0;
+const MAX_SLOT_SPACING: f32 = 6.25;
+const PANEL_MAX_WIDTH: f32 = 8.0;
+const PANEL_HEIGHT: f32 = 6.0;
+const PANEL_DEPTH: f32 = 0.2;
+/// Horizontal distance between characters, in cubes
+const CHARACTER_ADVANCE: f32 = 6.0;
+/// Largest title and number cube sizes; long strings shrink to fit the panel
+const TITLE_CUBE_SIZE: f32 = 0.16;
+/// Height a long category name may wrap into above the number
+const CATEGORY_TITLE_HEIGHT: f32 = 1.4;
+const SCORE_CUBE_SIZE: f32 = 0.4;
+const DETAIL_CUBE_SIZE: f32 = 0.12;
+const TITLE_Y: f32 = 1.9;
+const SCORE_Y: f32 = -0.8;
+/// The category board fits a winner line under a raised margin
+const CATEGORY_SCORE_Y: f32 = -0.2;
+const DETAIL_Y: f32 = -2.1;
+/// Most decimal places shown for dataset values
+const MAX_PRECISION: usize = 3;
+
+const TITLE_COLOR: Color = Color::rgb(1.0, 1.0, 0.33);
+/// Number lines are lit LEDs over a grid of dark off pixels
+const SCORE_GLOW: f32 = 1.5;
+const OFF_PIXEL_COLOR: Color = Color::rgb(0.12, 0.12, 0.12);
+const PANEL_COLOR: Color = Color::rgb(0.067, 0.067, 0.067);
+const WALL_COLOR: Color = Color::rgba(0.04, 0.04, 0.04, 0.9);
+const FRAME_COLOR: Color = Color::rgb(0.2, 0.2, 0.2);
+const SEPARATOR_COLOR: Color = Color::rgb(0.33, 0.33, 0.33);
+const PILLAR_COLOR: Color = Color::rgb(0.13, 0.13, 0.13);
+
+/// Which board a panel is
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoardSlot {
+    Entity(usize),
+    /// The center board for the selected category
+    Category,
+}
+
+/// A scoreboard panel; its title and number lines are children
+#[derive(Component)]
+pub struct ScoreBoard {
+    pub slot: BoardSlot,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineRole {
+    Title,
+    Score,
+    /// Winner and share line on the category board
+    Detail,
+}
+
+/// One line of TinyText cubes on a panel, with the text and color currently built
+#[derive(Component)]
+pub struct ScoreLine {
+    pub role: LineRole,
+    pub text: String,
+    pub color: Color,
+}
+
+/// Wall, frame, separators and pillars behind the scoreboards
+#[derive(Component)]
+pub struct StadiumWall;
+
+/// Easing curves for scoreboard count-ups
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Easing {
+    Linear,
+    EaseInCubic,
+    #[default]
+    EaseOutCubic,
+    EaseInOutCubic,
+}
+
+impl Easing {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "linear" => Some(Easing::Linear),
+            "ease-in" => Some(Easing::EaseInCubic),
+            "ease-out" => Some(Easing::EaseOutCubic),
+            "ease-in-out" => Some(Easing::EaseInOutCubic),
+            _ => None,
+        }
+    }
+
+    /// Eased progress for linear progress `t`, clamped to 0..=1
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseInCubic => t * t * t,
+            Easing::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOutCubic if t < 0.5 => 4.0 * t * t * t,
+            Easing::EaseInOutCubic => 1.0 - (-2.0 * t + 2.0).powi(3) / 2.0,
+        }
+    }
+}
+
+/// How scoreboard numbers move to new values. Disabled, numbers jump at once;
+/// presentation mode turns it on.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ScoreTween {
+    pub enabled: bool,
+    /// Seconds a count-up takes
+    pub duration: f32,
+    pub easing: Easing,
+}
+
+impl Default for ScoreTween {
+    fn default() -> Self {
+        Self { enabled: false, duration: 0.8, easing: Easing::default() }
+    }
+}
+
+/// The number a score line is counting toward and how far it has got
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct ScoreCount {
+    pub from: f32,
+    pub to: f32,
+    elapsed: f32,
+    duration: f32,
+}
+
+impl ScoreCount {
+    /// Value to display with `easing` applied
+    pub fn value(&self, easing: Easing) -> f32 {
+        if !self.is_running() {
+            return self.to;
+        }
+        self.from + (self.to - self.from) * easing.apply(self.elapsed / self.duration)
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.elapsed < self.duration
+    }
+
+    /// Starts counting from the currently displayed value toward `target`
+    fn retarget(&mut self, target: f32, duration: f32, easing: Easing) {
+        self.from = self.value(easing);
+        self.to = target;
+        self.elapsed = 0.0;
+        self.duration = duration;
+    }
+}
+
+/// What one panel shows
+struct BoardContent {
+    title: String,
+    score: String,
+    /// Number behind `score`, or `None` when the score is not a number (a tie)
+    count: Option<f32>,
+    /// Whether the number is shown with a sign (the category margin)
+    signed: bool,
+    detail: String,
+    score_color: Color,
+}
+
+impl BoardContent {
+    fn line(&self, role: LineRole) -> (&str, Color) {
+        match role {
+            LineRole::Title => (&self.title, TITLE_COLOR),
+            LineRole::Score => (&self.score, self.score_color),
+            LineRole::Detail => (&self.detail, self.score_color),
+        }
+    }
+}
+
+/// The selected category's outcome as shown on the center board
+#[derive(Clone, Debug, PartialEq)]
+pub struct CategorySummary {
+    /// Leading entity, or `None` when two or more entities share the top value
+    pub winner: Option<usize>,
+    /// Leader's value minus the runner-up's (0 on a tie)
+    pub margin: f32,
+    /// Leader's (or each tied entity's) percentage of the category total
+    pub share: f32,
+    /// Signed margin at the dataset's precision, or `TIE`
+    pub score: String,
+    /// Winner name and share, or the tied names
+    pub detail: String,
+    pub color: Color,
+}
+
+/// A score at `precision` decimal places
+fn format_score(value: f32, precision: usize, signed: bool) -> String {
+    if signed {
+        format!("{:+.*}", precision, value)
+    } else {
+        format!("{:.*}", precision, value)
+    }
+}
+
+/// Decimal places needed to show every value in the dataset exactly, capped at three
+pub fn dataset_precision(data: &[CompetitionData]) -> usize {
+    data.iter()
+        .flat_map(|entry| &entry.values)
+        .map(|value| value.to_string().split_once('.').map_or(0, |(_, fraction)| fraction.len()))
+        .max()
+        .unwrap_or(0)
+        .min(MAX_PRECISION)
+}
+
+/// Winner, signed margin and share for one category
+pub fn summarize_category(entities: &[EntityDef], entry: &CompetitionData, precision: usize) -> CategorySummary {
+    let mut ranked: Vec<usize> = (0..entry.values.len()).collect();
+    ranked.sort_by(|&a, &b| entry.values[b].total_cmp(&entry.values[a]));
+    let top = ranked.first().map_or(0.0, |&i| entry.values[i]);
+    let runner_up = ranked.get(1).map_or(0.0, |&i| entry.values[i]);
+    let total = entry.total();
+    let share = if total > 0.0 { top / total * 100.0 } else { 100.0 / ranked.len().max(1) as f32 };
+
+    if top > runner_up {
+        let winner = ranked[0];
+        CategorySummary {
+            winner: Some(winner),
+            margin: top - runner_up,
+            share,
+            score: format_score(top - runner_up, precision, true),
+            detail: format!("{} {:.0}%", entities[winner].name.to_uppercase(), share),
+            color: entities[winner].color,
+        }
+    } else {
+        let tied: Vec<String> = ranked
+            .iter()
+            .take_while(|&&i| entry.values[i] == top)
+            .map(|&i| entities[i].name.to_uppercase())
+            .collect();
+        CategorySummary {
+            winner: None,
+            margin: 0.0,
+            share,
+            score: "TIE".to_string(),
+            detail: format!("{} {:.0}%", tied.join(" = "), share),
+            color: TITLE_COLOR,
+        }
+    }
+}
+
+/// X position of every entity board and of the center category board
+fn board_slots(entity_count: usize) -> (Vec<f32>, f32, f32) {
+    // One board per entity with the category board in the middle slot
+    let spacing = (BOARD_ROW_WIDTH / entity_count as f32).min(MAX_SLOT_SPACING);
+    let slot_x = |slot: usize| (slot as f32 - entity_count as f32 / 2.0) * spacing;
+    let center_slot = entity_count / 2;
+    let entity_xs = (0..entity_count)
+        .map(|i| slot_x(if i < center_slot { i } else { i + 1 }))
+        .collect();
+    (entity_xs, slot_x(center_slot), spacing)
+}
+
+fn panel_width(spacing: f32) -> f32 {
+    (spacing - 0.35).min(PANEL_MAX_WIDTH)
+}
+
+fn solid(materials: &mut Assets<StandardMaterial>, color: Color) -> Handle<StandardMaterial> {
+    materials.add(StandardMaterial {
+        base_color: color,
+        alpha_mode: if color.a() < 1.0 { AlphaMode::Blend } else { AlphaMode::Opaque },
+        ..default()
+    })
+}
+
+/// Spawns the wall and one empty panel per board; `update_scoreboards` fills them in
+fn spawn_stadium(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    entity_count: usize,
+) {
+    spawn_stadium_wall(commands, meshes, materials, entity_count);
+
+    let (entity_xs, center_x, spacing) = board_slots(entity_count);
+    let width = panel_width(spacing);
+    let slots = entity_xs
+        .into_iter()
+        .enumerate()
+        .map(|(i, x)| (BoardSlot::Entity(i), x))
+        .chain([(BoardSlot::Category, center_x)]);
+    for (slot, x) in slots {
+        spawn_panel(commands, meshes, materials, slot, Vec3::new(x, BOARD_Y, 0.0), width);
+    }
+}
+
+/// Startup system for `spawn_stadium`
+pub fn setup_stadium(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    game_data: Res<GameData>,
+) {
+    spawn_stadium(&mut commands, &mut meshes, &mut materials, game_data.entity_count());
+}
+
+/// Spawns the wall behind the scoreboards, with one separator between each pair of boards
+fn spawn_stadium_wall(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    entity_count: usize,
+) {
+    let wall = commands
+        .spawn((SpatialBundle::default(), StadiumWall))
+        .id();
+    let mut part = |commands: &mut Commands, mesh: Mesh, color: Color, position: Vec3| {
+        let child = commands
+            .spawn(PbrBundle {
+                mesh: meshes.add(mesh),
+                material: solid(materials, color),
+                transform: Transform::from_translation(position),
+                ..default()
+            })
+            .id();
+        commands.entity(wall).add_child(child);
+    };
+
+    part(commands, Rectangle::new(35.0, 20.0).into(), WALL_COLOR, Vec3::new(0.0, BOARD_Y - 2.0, -4.0));
+    part(commands, Cuboid::new(32.0, 8.0, 0.5).into(), FRAME_COLOR, Vec3::new(0.0, BOARD_Y, -1.0));
+    for x in [-18.0, 18.0] {
+        part(commands, Cuboid::new(1.0, 20.0, 1.0).into(), PILLAR_COLOR, Vec3::new(x, BOARD_Y - 2.0, -3.0));
+    }
+
+    let (entity_xs, center_x, _) = board_slots(entity_count);
+    let mut slots = entity_xs;
+    slots.push(center_x);
+    slots.sort_by(f32::total_cmp);
+    for pair in slots.windows(2) {
+        let x = (pair[0] + pair[1]) / 2.0;
+        part(commands, Cuboid::new(0.3, 8.0, 1.0).into(), SEPARATOR_COLOR, Vec3::new(x, BOARD_Y, -0.5));
+    }
+}
+
+/// Respawns the wall and panels when a rebuild changed the number of entities.
+/// Otherwise the panels stay and `update_scoreboards` rewrites their lines.
+pub fn rebuild_stadium(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut events: EventReader<RebuildCharts>,
+    game_data: Res<GameData>,
+    walls: Query<Entity, With<StadiumWall>>,
+    boards: Query<(Entity, &ScoreBoard)>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+    let entity_boards = boards.iter().filter(|(_, board)| matches!(board.slot, BoardSlot::Entity(_))).count();
+    if entity_boards == game_data.entity_count() {
+        return;
+    }
+    for entity in walls.iter().chain(boards.iter().map(|(entity, _)| entity)) {
+        commands.entity(entity).despawn_recursive();
+    }
+    spawn_stadium(&mut commands, &mut meshes, &mut materials, game_data.entity_count());
+}
+
+/// What the board in `slot` should show, or `None` for a board left over from a
+/// dataset with more entities (it is replaced on the next rebuild)
+fn board_content(game_data: &GameData, slot: BoardSlot) -> Option<BoardContent> {
+    let selected = &game_data.data[game_data.selected_category];
+    let precision = dataset_precision(&game_data.data);
+    match slot {
+        BoardSlot::Entity(i) => {
+            let entity = game_data.entities.get(i)?;
+            let value = *selected.values.get(i)?;
+            Some(BoardContent {
+                title: entity.name.to_uppercase(),
+                score: format_score(value, precision, false),
+                count: Some(value),
+                signed: false,
+                detail: String::new(),
+                score_color: entity.color,
+            })
+        }
+        // Category name, winner's margin and share
+        BoardSlot::Category => {
+            let summary = summarize_category(&game_data.entities, selected, precision);
+            Some(BoardContent {
+                title: selected.name.to_uppercase(),
+                score: summary.score,
+                count: summary.winner.map(|_| summary.margin),
+                signed: true,
+                detail: summary.detail,
+                score_color: summary.color,
+            })
+        }
+    }
+}
+
+/// Updates the text of every line whose text or color no longer matches the
+/// selection, stepping any running count-ups. Panels and unchanged lines are
+/// left alone.
+pub fn update_scoreboards(
+    game_data: Res<GameData>,
+    tween: Res<ScoreTween>,
+    time: Res<Time>,
+    boards: Query<(Ref<ScoreBoard>, &Children)>,
+    mut lines: Query<(&mut ScoreLine, &mut TinyText3d, Option<&mut ScoreCount>)>,
+) {
+    let counting = lines.iter().any(|(_, _, count)| count.is_some_and(|count| count.is_running()));
+    if !game_data.is_changed() && !counting && !boards.iter().any(|(board, _)| board.is_added()) {
+        return;
+    }
+    let precision = dataset_precision(&game_data.data);
+
+    for (board, children) in boards.iter() {
+        let Some(content) = board_content(&game_data, board.slot) else {
+            continue;
+        };
+        // New panels show their first values at once
+        let duration = if tween.enabled && !board.is_added() { tween.duration } else { 0.0 };
+        for &child in children.iter() {
+            let Ok((mut line, mut tiny_text, count)) = lines.get_mut(child) else {
+                continue;
+            };
+            let (text, color) = content.line(line.role);
+            let mut text = text.to_string();
+            if let Some(mut count) = count {
+                match content.count {
+                    Some(target) if count.to != target => count.retarget(target, duration, tween.easing),
+                    Some(_) if count.is_running() => count.elapsed += time.delta_seconds(),
+                    Some(_) => {}
+                    // Counting restarts from zero after a tie
+                    None if count.to != 0.0 || count.is_running() => *count = ScoreCount::default(),
+                    None => {}
+                }
+                if content.count.is_some() {
+                    text = format_score(count.value(tween.easing), precision, content.signed);
+                }
+            }
+            if line.text == text && line.color == color {
+                continue;
+            }
+            tiny_text.text.clone_from(&text);
+            tiny_text.color = color;
+            line.text = text;
+            line.color = color;
+        }
+    }
+}
+
+/// A dark panel with an empty title line above an empty number line, plus a
+/// winner line on the category board
+fn spawn_panel(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    slot: BoardSlot,
+    position: Vec3,
+    width: f32,
+) {
+    commands
+        .spawn((
+            PbrBundle {
+                mesh: meshes.add(Cuboid::new(width, PANEL_HEIGHT, PANEL_DEPTH)),
+                material: solid(materials, PANEL_COLOR),
+                transform: Transform::from_translation(position),
+                ..default()
+            },
+            ScoreBoard { slot },
+        ))
+        .with_children(|panel| {
+            let lines: &[(LineRole, f32, f32)] = match slot {
+                BoardSlot::Entity(_) => &[
+                    (LineRole::Title, TITLE_Y, TITLE_CUBE_SIZE),
+                    (LineRole::Score, SCORE_Y, SCORE_CUBE_SIZE),
+                ],
+                BoardSlot::Category => &[
+                    (LineRole::Title, TITLE_Y, TITLE_CUBE_SIZE),
+                    (LineRole::Score, CATEGORY_SCORE_Y, SCORE_CUBE_SIZE),
+                    (LineRole::Detail, DETAIL_Y, DETAIL_CUBE_SIZE),
+                ],
+            };
+            for &(role, y, max_cube_size) in lines {
+                // Long strings shrink to the panel width; the category name may
+                // also wrap onto a second line
+                let text = TinyText3d::default()
+                    .with_scale(Vec3::splat(max_cube_size))
+                    .with_spacing(Vec3::X * CHARACTER_ADVANCE * max_cube_size)
+                    .with_align(TextAlign::Center, VerticalAlign::Middle)
+                    .with_mode(TextMeshMode::Merged);
+                let text = match (slot, role) {
+                    (BoardSlot::Category, LineRole::Title) => {
+                        text.with_wrap_width(width * 0.9).with_fit(Vec2::new(width * 0.9, CATEGORY_TITLE_HEIGHT))
+                    }
+                    (_, LineRole::Score) => text
+                        .with_fit(Vec2::new(width * 0.9, f32::INFINITY))
+                        .with_material(TextMaterial::led(SCORE_GLOW).with_off_pixels(OFF_PIXEL_COLOR)),
+                    _ => text.with_fit(Vec2::new(width * 0.9, f32::INFINITY)),
+                };
+                // Full-size cubes rest on the panel face
+                let mut line = panel.spawn((
+                    TinyText3dBundle::new(text, Transform::from_xyz(0.0, y, PANEL_DEPTH / 2.0 + max_cube_size / 2.0)),
+                    ScoreLine { role, text: String::new(), color: Color::NONE },
+                ));
+                if role == LineRole::Score {
+                    line.insert(ScoreCount::default());
+                }
+            }
+        });
+}
@@ -0,0 +1,114 @@
+//! Floating tooltip for the slice under the cursor.
+// Shows the hovered category's values for every entity together with the two
+// shares that shape the hovered slice: its angle (share of the entity's total)
+// and its radius (share of the category total).
+
+use bevy::prelude::*;
+
+use super::chart::{angular_share, radial_share};
+use super::picking::SlicePicker;
+use super::{GameData, PieSlice};
+
+/// Offset from the cursor so the tooltip does not cover the hovered slice
+const CURSOR_OFFSET: Vec2 = Vec2::new(16.0, 16.0);
+
+#[derive(Component)]
+pub struct SliceTooltip;
+
+#[derive(Component)]
+pub struct SliceTooltipText;
+
+/// Spawns the hidden tooltip panel
+pub fn spawn_tooltip(mut commands: Commands) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    display: Display::None,
+                    padding: UiRect::all(Val::Px(8.0)),
+                    ..default()
+                },
+                background_color: Color::rgba(0.05, 0.05, 0.05, 0.9).into(),
+                z_index: ZIndex::Global(10),
+                ..default()
+            },
+            SliceTooltip,
+        ))
+        .with_children(|tooltip| {
+            tooltip.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 16.0,
+                        color: Color::rgb(0.9, 0.9, 0.9),
+                        ..default()
+                    },
+                ),
+                SliceTooltipText,
+            ));
+        });
+}
+
+/// Tooltip text for one entity's slice of a category
+pub fn tooltip_text(game_data: &GameData, category_index: usize, entity_index: usize) -> String {
+    let entry = &game_data.data[category_index];
+    let entity = &game_data.entities[entity_index].name;
+    let mut lines = vec![entry.name.clone()];
+    for (i, (def, value)) in game_data.entities.iter().zip(&entry.values).enumerate() {
+        let marker = if i == entity_index { "▶" } else { " " };
+        lines.push(format!("{} {}: {}", marker, def.name, value));
+    }
+    lines.push(format!(
+        "Angle: {:.1}% of the {} total",
+        angular_share(&game_data.data, category_index, entity_index) * 100.0,
+        entity
+    ));
+    lines.push(format!(
+        "Radius: {:.1}% of the category total",
+        radial_share(entry, entity_index) * 100.0
+    ));
+    lines.join("\n")
+}
+
+/// Follows the cursor while a slice is hovered and hides otherwise
+pub(super) fn update_tooltip(
+    picker: Res<SlicePicker>,
+    game_data: Res<GameData>,
+    windows: Query<&Window>,
+    slices: Query<&PieSlice>,
+    mut tooltips: Query<&mut Style, With<SliceTooltip>>,
+    mut texts: Query<&mut Text, With<SliceTooltipText>>,
+) {
+    let cursor = windows.single().cursor_position();
+    let hovered = picker
+        .hovered
+        .and_then(|entity| slices.get(entity).ok())
+        .filter(|slice| slice.is_current(&game_data))
+        .zip(cursor);
+
+    for mut style in tooltips.iter_mut() {
+        let Some((_, cursor)) = hovered else {
+            if style.display != Display::None {
+                style.display = Display::None;
+            }
+            continue;
+        };
+        let (left, top) = (Val::Px(cursor.x + CURSOR_OFFSET.x), Val::Px(cursor.y + CURSOR_OFFSET.y));
+        if style.display != Display::Flex || style.left != left || style.top != top {
+            style.display = Display::Flex;
+            style.left = left;
+            style.top = top;
+        }
+    }
+
+    let Some((slice, _)) = hovered else {
+        return;
+    };
+    let value = tooltip_text(&game_data, slice.category_index, slice.entity_index);
+    for mut text in texts.iter_mut() {
+        if text.sections[0].value != value {
+            text.sections[0].value = value.clone();
+        }
+    }
+}
@@ -1,13 +1,40 @@
-//! TinyText: 3D cube-based vector font for all printable Latin characters (ASCII)
+//! TinyText: 3D cube-based vector font for ASCII, Latin-1 and common symbols
 // Supports: adjustable offset, orientation, and scale using stacked cubes
-// Usage: TinyText::spawn_text(commands, meshes, materials, "Hello!", origin, scale, orientation, offset, color)
+// Usage: from a system taking `mut text: TinyTextSpawner`,
+//   text.spawn_text("Hello!", origin, scale, orientation, offset, color)
+// The font, cube mesh and per-color materials are shared resources built once
+// (a color's material is dropped once no text uses it); the font is read
+// from assets/fonts/tiny_font.txt or any BDF, PSF or grid font file via
+// TinyFont::load (see font_file). Characters the font lacks are drawn as a
+// hollow box, or left out after TinyFont::set_missing_glyph(None)
+// Or add TinyTextPlugin and spawn a TinyText3dBundle; its cubes are children that
+// are rebuilt whenever the TinyText3d component changes, either as one entity
+// per cube or as a single merged mesh per string, with a fixed offset or
+// proportional glyph widths and kerning (TextAdvance). TinyText3d also lays
+// out multiple lines with wrapping and shrink-to-fit (see layout), and can be
+// extruded, drawn as dots or beveled cubes, and given a shadow (see voxel),
+// colored per character or by gradient and lit as LEDs (see color)
 
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
-use bevy::math::primitives::Cuboid;
+use bevy::transform::TransformSystem;
+
+pub mod color;
+pub mod font_file;
+pub mod layout;
+pub mod voxel;
+
+pub use color::{ColorSpan, TextFill, TextMaterial};
+pub use font_file::FontError;
+pub use layout::{TextBounds, TextLayout};
+pub use voxel::{GlyphVoxels, TextShadow, TextStyle, VoxelShape};
 
 
 /// Cube position definition for building letters
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct CubePos {
     pub x: i32,
     pub y: i32,
@@ -16,837 +43,286 @@ pub struct CubePos {
 /// Glyph definition: a vector of cube positions
 pub type Glyph = Vec<CubePos>;
 
-/// 3D cube-based font for printable ASCII (32-126)
+/// Cell size of a standard glyph in cubes (some punctuation overhangs it)
+pub const GLYPH_WIDTH: i32 = 5;
+pub const GLYPH_HEIGHT: i32 = 7;
+
+/// Empty cubes left between glyphs by `TextAdvance::Proportional`
+pub const PROPORTIONAL_GAP: i32 = 1;
+/// Proportional advance of a glyph without cubes, such as the space
+pub const BLANK_ADVANCE: i32 = 3;
+
+/// Horizontal metrics of a glyph, measured from its `CubePos` bounds
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GlyphMetrics {
+    /// Leftmost cube column
+    pub min_x: i32,
+    /// Columns from the leftmost to the rightmost cube; 0 for a blank glyph
+    pub width: i32,
+    /// Columns the pen moves on by in proportional layout
+    pub advance: i32,
+}
+
+impl GlyphMetrics {
+    pub fn measure(glyph: &[CubePos]) -> Self {
+        match (glyph.iter().map(|cube| cube.x).min(), glyph.iter().map(|cube| cube.x).max()) {
+            (Some(min_x), Some(max_x)) => {
+                let width = max_x - min_x + 1;
+                Self { min_x, width, advance: width + PROPORTIONAL_GAP }
+            }
+            _ => Self { min_x: 0, width: 0, advance: BLANK_ADVANCE },
+        }
+    }
+}
+
+/// 3D cube-based font, shared as a resource. Characters without a glyph are
+/// drawn as the missing glyph, if the font has one.
+#[derive(Resource, Clone, Debug)]
 pub struct TinyFont {
-    glyphs: [Option<Glyph>; 95], // ASCII 32-126
+    glyphs: HashMap<char, Glyph>,
+    missing: Option<Glyph>,
+    /// Extra columns between a pair of characters in proportional layout
+    kerning: HashMap<(char, char), i32>,
+}
+
+impl Default for TinyFont {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl TinyFont {
+    /// The built-in font, read from assets/fonts/tiny_font.txt at compile time
     pub fn new() -> Self {
-        let mut glyphs: [Option<Glyph>; 95] = std::array::from_fn(|_| None);
-        
-        // SPACE (32)
-        glyphs[0] = Some(vec![]);
-        
-        // ! (33)
-        glyphs[1] = Some(vec![
-            CubePos { x: 2, y: 0 },
-            CubePos { x: 2, y: 1 },
-            CubePos { x: 2, y: 2 },
-            CubePos { x: 2, y: 3 },
-            CubePos { x: 2, y: 5 },
-            CubePos { x: 2, y: 6 },
-            CubePos { x: 2, y: 7 },
-        ]);
-        
-        // " (34)
-        glyphs[2] = Some(vec![
-            CubePos { x: 1, y: 5 },
-            CubePos { x: 1, y: 6 },
-            CubePos { x: 1, y: 7 },
-            CubePos { x: 3, y: 5 },
-            CubePos { x: 3, y: 6 },
-            CubePos { x: 3, y: 7 },
-        ]);
-        
-        // # (35)
-        glyphs[3] = Some(vec![
-            CubePos { x: 1, y: 1 }, CubePos { x: 1, y: 2 }, CubePos { x: 1, y: 3 },
-            CubePos { x: 1, y: 4 }, CubePos { x: 1, y: 5 }, CubePos { x: 1, y: 6 },
-            CubePos { x: 3, y: 1 }, CubePos { x: 3, y: 2 }, CubePos { x: 3, y: 3 },
-            CubePos { x: 3, y: 4 }, CubePos { x: 3, y: 5 }, CubePos { x: 3, y: 6 },
-            CubePos { x: 0, y: 2 }, CubePos { x: 2, y: 2 }, CubePos { x: 4, y: 2 },
-            CubePos { x: 0, y: 5 }, CubePos { x: 2, y: 5 }, CubePos { x: 4, y: 5 },
-        ]);
-        
-        // $ (36)
-        glyphs[4] = Some(vec![
-            CubePos { x: 2, y: 0 }, CubePos { x: 2, y: 1 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 2, y: 3 }, CubePos { x: 2, y: 4 }, CubePos { x: 2, y: 5 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 2, y: 7 },
-            CubePos { x: 0, y: 1 }, CubePos { x: 1, y: 1 }, CubePos { x: 3, y: 1 },
-            CubePos { x: 0, y: 2 }, CubePos { x: 1, y: 3 }, CubePos { x: 3, y: 3 },
-            CubePos { x: 0, y: 4 }, CubePos { x: 1, y: 5 }, CubePos { x: 3, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 6 }, CubePos { x: 3, y: 6 },
-        ]);
-        
-        // % (37)
-        glyphs[5] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 1, y: 1 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 4, y: 4 }, CubePos { x: 5, y: 5 },
-            CubePos { x: 6, y: 6 }, CubePos { x: 7, y: 7 },
-            CubePos { x: 0, y: 5 }, CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 6 },
-            CubePos { x: 1, y: 5 }, CubePos { x: 6, y: 1 }, CubePos { x: 6, y: 2 },
-            CubePos { x: 7, y: 2 }, CubePos { x: 7, y: 1 },
-        ]);
-        
-        // & (38)
-        glyphs[6] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 0, y: 1 }, CubePos { x: 4, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 3 }, CubePos { x: 0, y: 4 },
-            CubePos { x: 3, y: 4 }, CubePos { x: 0, y: 5 }, CubePos { x: 4, y: 5 },
-            CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 }, CubePos { x: 4, y: 6 },
-            CubePos { x: 5, y: 6 },
-        ]);
-        
-        // ' (39)
-        glyphs[7] = Some(vec![
-            CubePos { x: 2, y: 5 },
-            CubePos { x: 2, y: 6 },
-            CubePos { x: 2, y: 7 },
-        ]);
-        
-        // ( (40)
-        glyphs[8] = Some(vec![
-            CubePos { x: 2, y: 1 }, CubePos { x: 1, y: 2 }, CubePos { x: 1, y: 3 },
-            CubePos { x: 1, y: 4 }, CubePos { x: 1, y: 5 }, CubePos { x: 2, y: 6 },
-        ]);
-        
-        // ) (41)
-        glyphs[9] = Some(vec![
-            CubePos { x: 1, y: 1 }, CubePos { x: 2, y: 2 }, CubePos { x: 2, y: 3 },
-            CubePos { x: 2, y: 4 }, CubePos { x: 2, y: 5 }, CubePos { x: 1, y: 6 },
-        ]);
-        
-        // * (42)
-        glyphs[10] = Some(vec![
-            CubePos { x: 2, y: 2 }, CubePos { x: 2, y: 3 }, CubePos { x: 2, y: 4 },
-            CubePos { x: 1, y: 3 }, CubePos { x: 3, y: 3 }, CubePos { x: 1, y: 2 },
-            CubePos { x: 3, y: 4 }, CubePos { x: 1, y: 4 }, CubePos { x: 3, y: 2 },
-        ]);
-        
-        // + (43)
-        glyphs[11] = Some(vec![
-            CubePos { x: 2, y: 1 }, CubePos { x: 2, y: 2 }, CubePos { x: 2, y: 3 },
-            CubePos { x: 2, y: 4 }, CubePos { x: 2, y: 5 }, CubePos { x: 0, y: 3 },
-            CubePos { x: 1, y: 3 }, CubePos { x: 3, y: 3 }, CubePos { x: 4, y: 3 },
-        ]);
-        
-        // , (44)
-        glyphs[12] = Some(vec![
-            CubePos { x: 2, y: 0 },
-            CubePos { x: 1, y: 1 },
-        ]);
-        
-        // - (45)
-        glyphs[13] = Some(vec![
-            CubePos { x: 0, y: 3 }, CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 3 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 4, y: 3 },
-        ]);
-        
-        // . (46)
-        glyphs[14] = Some(vec![
-            CubePos { x: 2, y: 0 },
-        ]);
-        
-        // / (47)
-        glyphs[15] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 1, y: 1 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 4, y: 4 }, CubePos { x: 5, y: 5 },
-            CubePos { x: 6, y: 6 }, CubePos { x: 7, y: 7 },
-        ]);
-        
-        // 0 (48)
-        glyphs[16] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 0, y: 1 }, CubePos { x: 4, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 4, y: 2 }, CubePos { x: 0, y: 3 }, CubePos { x: 4, y: 3 },
-            CubePos { x: 0, y: 4 }, CubePos { x: 4, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 4, y: 5 }, CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 },
-            CubePos { x: 3, y: 6 },
-        ]);
-        
-        // 1 (49)
-        glyphs[17] = Some(vec![
-            CubePos { x: 2, y: 0 }, CubePos { x: 2, y: 1 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 2, y: 3 }, CubePos { x: 2, y: 4 }, CubePos { x: 2, y: 5 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 1, y: 5 }, CubePos { x: 0, y: 0 },
-            CubePos { x: 1, y: 0 }, CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 0 },
-        ]);
-        
-        // 2 (50)
-        glyphs[18] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 0 }, CubePos { x: 0, y: 1 },
-            CubePos { x: 1, y: 2 }, CubePos { x: 2, y: 3 }, CubePos { x: 3, y: 4 },
-            CubePos { x: 4, y: 5 }, CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 6 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 },
-        ]);
-        
-        // 3 (51)
-        glyphs[19] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 2 },
-            CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 3 }, CubePos { x: 3, y: 3 },
-            CubePos { x: 4, y: 4 }, CubePos { x: 4, y: 5 }, CubePos { x: 0, y: 6 },
-            CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 },
-        ]);
-        
-        // 4 (52)
-        glyphs[20] = Some(vec![
-            CubePos { x: 0, y: 3 }, CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 3 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 4, y: 3 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 3, y: 1 }, CubePos { x: 3, y: 2 }, CubePos { x: 3, y: 4 },
-            CubePos { x: 3, y: 5 }, CubePos { x: 3, y: 6 }, CubePos { x: 0, y: 4 },
-            CubePos { x: 1, y: 5 }, CubePos { x: 2, y: 6 },
-        ]);
-        
-        // 5 (53)
-        glyphs[21] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 3 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 },
-            CubePos { x: 3, y: 6 }, CubePos { x: 4, y: 6 },
-        ]);
-        
-        // 6 (54)
-        glyphs[22] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 0, y: 1 }, CubePos { x: 4, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 4, y: 2 }, CubePos { x: 0, y: 3 }, CubePos { x: 1, y: 3 },
-            CubePos { x: 2, y: 3 }, CubePos { x: 3, y: 3 }, CubePos { x: 0, y: 4 },
-            CubePos { x: 0, y: 5 }, CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 },
-            CubePos { x: 3, y: 6 },
-        ]);
-        
-        // 7 (55)
-        glyphs[23] = Some(vec![
-            CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 },
-            CubePos { x: 3, y: 6 }, CubePos { x: 4, y: 6 }, CubePos { x: 4, y: 5 },
-            CubePos { x: 3, y: 4 }, CubePos { x: 2, y: 3 }, CubePos { x: 1, y: 2 },
-            CubePos { x: 1, y: 1 }, CubePos { x: 1, y: 0 },
-        ]);
-        
-        // 8 (56)
-        glyphs[24] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 0, y: 1 }, CubePos { x: 4, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 4, y: 2 }, CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 3 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 4, y: 4 },
-            CubePos { x: 0, y: 5 }, CubePos { x: 4, y: 5 }, CubePos { x: 1, y: 6 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 },
-        ]);
-        
-        // 9 (57)
-        glyphs[25] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 2 }, CubePos { x: 0, y: 3 },
-            CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 3 }, CubePos { x: 3, y: 3 },
-            CubePos { x: 4, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 4, y: 4 },
-            CubePos { x: 0, y: 5 }, CubePos { x: 4, y: 5 }, CubePos { x: 1, y: 6 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 },
-        ]);
-        
-        // : (58)
-        glyphs[26] = Some(vec![
-            CubePos { x: 2, y: 1 },
-            CubePos { x: 2, y: 5 },
-        ]);
-        
-        // ; (59)
-        glyphs[27] = Some(vec![
-            CubePos { x: 2, y: 0 }, CubePos { x: 1, y: 1 },
-            CubePos { x: 2, y: 5 },
-        ]);
-        
-        // < (60)
-        glyphs[28] = Some(vec![
-            CubePos { x: 4, y: 1 }, CubePos { x: 3, y: 2 }, CubePos { x: 2, y: 3 },
-            CubePos { x: 1, y: 4 }, CubePos { x: 2, y: 5 }, CubePos { x: 3, y: 6 },
-            CubePos { x: 4, y: 7 },
-        ]);
-        
-        // = (61)
-        glyphs[29] = Some(vec![
-            CubePos { x: 0, y: 2 }, CubePos { x: 1, y: 2 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 3, y: 2 }, CubePos { x: 4, y: 2 }, CubePos { x: 0, y: 4 },
-            CubePos { x: 1, y: 4 }, CubePos { x: 2, y: 4 }, CubePos { x: 3, y: 4 },
-            CubePos { x: 4, y: 4 },
-        ]);
-        
-        // > (62)
-        glyphs[30] = Some(vec![
-            CubePos { x: 0, y: 1 }, CubePos { x: 1, y: 2 }, CubePos { x: 2, y: 3 },
-            CubePos { x: 3, y: 4 }, CubePos { x: 2, y: 5 }, CubePos { x: 1, y: 6 },
-            CubePos { x: 0, y: 7 },
-        ]);
-        
-        // ? (63)
-        glyphs[31] = Some(vec![
-            CubePos { x: 0, y: 5 }, CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 },
-            CubePos { x: 3, y: 6 }, CubePos { x: 4, y: 5 }, CubePos { x: 4, y: 4 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 2, y: 3 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 2, y: 0 },
-        ]);
-        
-        // @ (64)
-        glyphs[32] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 4, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 5, y: 1 },
-            CubePos { x: 0, y: 2 }, CubePos { x: 2, y: 2 }, CubePos { x: 3, y: 2 },
-            CubePos { x: 4, y: 2 }, CubePos { x: 5, y: 2 }, CubePos { x: 0, y: 3 },
-            CubePos { x: 2, y: 3 }, CubePos { x: 4, y: 3 }, CubePos { x: 0, y: 4 },
-            CubePos { x: 2, y: 4 }, CubePos { x: 3, y: 4 }, CubePos { x: 4, y: 4 },
-            CubePos { x: 1, y: 5 }, CubePos { x: 2, y: 5 }, CubePos { x: 3, y: 5 },
-            CubePos { x: 4, y: 5 },
-        ]);
-        
-        // A (65)
-        glyphs[33] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 4, y: 0 }, CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 2 },
-            CubePos { x: 4, y: 3 }, CubePos { x: 4, y: 4 }, CubePos { x: 4, y: 5 },
-            CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 },
-            CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 3 }, CubePos { x: 3, y: 3 },
-        ]);
-        
-        // B (66)
-        glyphs[34] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 2 },
-            CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 3 }, CubePos { x: 3, y: 3 },
-            CubePos { x: 4, y: 4 }, CubePos { x: 4, y: 5 }, CubePos { x: 1, y: 6 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 },
-        ]);
-        
-        // C (67)
-        glyphs[35] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 4, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 },
-            CubePos { x: 4, y: 6 },
-        ]);
-        
-        // D (68)
-        glyphs[36] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 2 },
-            CubePos { x: 4, y: 3 }, CubePos { x: 4, y: 4 }, CubePos { x: 4, y: 5 },
-            CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 },
-        ]);
-        
-        // E (69)
-        glyphs[37] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 0 }, CubePos { x: 1, y: 3 },
-            CubePos { x: 2, y: 3 }, CubePos { x: 3, y: 3 }, CubePos { x: 1, y: 6 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 }, CubePos { x: 4, y: 6 },
-        ]);
-        
-        // F (70)
-        glyphs[38] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 3 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 },
-            CubePos { x: 3, y: 6 }, CubePos { x: 4, y: 6 },
-        ]);
-        
-        // G (71)
-        glyphs[39] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 4, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 },
-            CubePos { x: 4, y: 6 }, CubePos { x: 4, y: 5 }, CubePos { x: 4, y: 4 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 4, y: 3 },
-        ]);
-        
-        // H (72)
-        glyphs[40] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 4, y: 0 }, CubePos { x: 4, y: 1 },
-            CubePos { x: 4, y: 2 }, CubePos { x: 4, y: 3 }, CubePos { x: 4, y: 4 },
-            CubePos { x: 4, y: 5 }, CubePos { x: 4, y: 6 }, CubePos { x: 1, y: 3 },
-            CubePos { x: 2, y: 3 }, CubePos { x: 3, y: 3 },
-        ]);
-        
-        // I (73)
-        glyphs[41] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 0 }, CubePos { x: 2, y: 1 },
-            CubePos { x: 2, y: 2 }, CubePos { x: 2, y: 3 }, CubePos { x: 2, y: 4 },
-            CubePos { x: 2, y: 5 }, CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 6 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 }, CubePos { x: 4, y: 6 },
-        ]);
-        
-        // J (74)
-        glyphs[42] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 1 }, CubePos { x: 3, y: 2 }, CubePos { x: 3, y: 3 },
-            CubePos { x: 3, y: 4 }, CubePos { x: 3, y: 5 }, CubePos { x: 0, y: 6 },
-            CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 },
-            CubePos { x: 4, y: 6 },
-        ]);
-        
-        // K (75)
-        glyphs[43] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 4, y: 0 }, CubePos { x: 3, y: 1 },
-            CubePos { x: 2, y: 2 }, CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 4 },
-            CubePos { x: 3, y: 5 }, CubePos { x: 4, y: 6 },
-        ]);
-        
-        // L (76)
-        glyphs[44] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 0 },
-        ]);
-        
-        // M (77)
-        glyphs[45] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 6, y: 0 }, CubePos { x: 6, y: 1 },
-            CubePos { x: 6, y: 2 }, CubePos { x: 6, y: 3 }, CubePos { x: 6, y: 4 },
-            CubePos { x: 6, y: 5 }, CubePos { x: 6, y: 6 }, CubePos { x: 1, y: 5 },
-            CubePos { x: 2, y: 4 }, CubePos { x: 3, y: 3 }, CubePos { x: 4, y: 4 },
-            CubePos { x: 5, y: 5 },
-        ]);
-        
-        // N (78)
-        glyphs[46] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 4, y: 0 }, CubePos { x: 4, y: 1 },
-            CubePos { x: 4, y: 2 }, CubePos { x: 4, y: 3 }, CubePos { x: 4, y: 4 },
-            CubePos { x: 4, y: 5 }, CubePos { x: 4, y: 6 }, CubePos { x: 1, y: 1 },
-            CubePos { x: 2, y: 2 }, CubePos { x: 3, y: 3 },
-        ]);
-        
-        // O (79)
-        glyphs[47] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 0, y: 1 }, CubePos { x: 4, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 4, y: 2 }, CubePos { x: 0, y: 3 }, CubePos { x: 4, y: 3 },
-            CubePos { x: 0, y: 4 }, CubePos { x: 4, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 4, y: 5 }, CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 },
-            CubePos { x: 3, y: 6 },
-        ]);
-        
-        // P (80)
-        glyphs[48] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 3 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 4, y: 4 }, CubePos { x: 4, y: 5 },
-            CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 },
-        ]);
-        
-        // Q (81)
-        glyphs[49] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 0, y: 1 }, CubePos { x: 4, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 4, y: 2 }, CubePos { x: 0, y: 3 }, CubePos { x: 4, y: 3 },
-            CubePos { x: 0, y: 4 }, CubePos { x: 4, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 4, y: 5 }, CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 },
-            CubePos { x: 3, y: 6 }, CubePos { x: 3, y: 1 }, CubePos { x: 4, y: 0 },
-        ]);
-        
-        // R (82)
-        glyphs[50] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 3 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 4, y: 4 }, CubePos { x: 4, y: 5 },
-            CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 },
-            CubePos { x: 2, y: 2 }, CubePos { x: 3, y: 1 }, CubePos { x: 4, y: 0 },
-        ]);
-        
-        // S (83)
-        glyphs[51] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 2 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 2, y: 3 }, CubePos { x: 1, y: 3 },
-            CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 }, CubePos { x: 1, y: 6 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 }, CubePos { x: 4, y: 6 },
-        ]);
-        
-        // T (84)
-        glyphs[52] = Some(vec![
-            CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 },
-            CubePos { x: 3, y: 6 }, CubePos { x: 4, y: 6 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 2, y: 1 }, CubePos { x: 2, y: 2 }, CubePos { x: 2, y: 3 },
-            CubePos { x: 2, y: 4 }, CubePos { x: 2, y: 5 },
-        ]);
-        
-        // U (85)
-        glyphs[53] = Some(vec![
-            CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 }, CubePos { x: 0, y: 3 },
-            CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 }, CubePos { x: 0, y: 6 },
-            CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 2 }, CubePos { x: 4, y: 3 },
-            CubePos { x: 4, y: 4 }, CubePos { x: 4, y: 5 }, CubePos { x: 4, y: 6 },
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-        ]);
-        
-        // V (86)
-        glyphs[54] = Some(vec![
-            CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 }, CubePos { x: 0, y: 6 },
-            CubePos { x: 1, y: 2 }, CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 2, y: 1 }, CubePos { x: 3, y: 2 }, CubePos { x: 3, y: 3 },
-            CubePos { x: 4, y: 4 }, CubePos { x: 4, y: 5 }, CubePos { x: 4, y: 6 },
-        ]);
-        
-        // W (87)
-        glyphs[55] = Some(vec![
-            CubePos { x: 0, y: 2 }, CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 },
-            CubePos { x: 0, y: 5 }, CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 0 },
-            CubePos { x: 1, y: 1 }, CubePos { x: 2, y: 2 }, CubePos { x: 2, y: 3 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 3, y: 1 }, CubePos { x: 4, y: 2 },
-            CubePos { x: 4, y: 3 }, CubePos { x: 4, y: 4 }, CubePos { x: 4, y: 5 },
-            CubePos { x: 4, y: 6 },
-        ]);
-        
-        // X (88)
-        glyphs[56] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 1, y: 1 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 2, y: 3 }, CubePos { x: 2, y: 4 }, CubePos { x: 3, y: 5 },
-            CubePos { x: 4, y: 6 }, CubePos { x: 4, y: 0 }, CubePos { x: 3, y: 1 },
-            CubePos { x: 1, y: 5 }, CubePos { x: 0, y: 6 },
-        ]);
-        
-        // Y (89)
-        glyphs[57] = Some(vec![
-            CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 5 }, CubePos { x: 2, y: 4 },
-            CubePos { x: 2, y: 3 }, CubePos { x: 2, y: 2 }, CubePos { x: 2, y: 1 },
-            CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 5 }, CubePos { x: 4, y: 6 },
-        ]);
-        
-        // Z (90)
-        glyphs[58] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 0 }, CubePos { x: 4, y: 1 },
-            CubePos { x: 3, y: 2 }, CubePos { x: 2, y: 3 }, CubePos { x: 1, y: 4 },
-            CubePos { x: 0, y: 5 }, CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 6 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 }, CubePos { x: 4, y: 6 },
-        ]);
-        
-        // [ (91)
-        glyphs[59] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 1, y: 1 }, CubePos { x: 1, y: 2 },
-            CubePos { x: 1, y: 3 }, CubePos { x: 1, y: 4 }, CubePos { x: 1, y: 5 },
-            CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 },
-        ]);
-        
-        // \ (92)
-        glyphs[60] = Some(vec![
-            CubePos { x: 0, y: 7 }, CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 5 },
-            CubePos { x: 3, y: 4 }, CubePos { x: 4, y: 3 }, CubePos { x: 5, y: 2 },
-            CubePos { x: 6, y: 1 }, CubePos { x: 7, y: 0 },
-        ]);
-        
-        // ] (93)
-        glyphs[61] = Some(vec![
-            CubePos { x: 3, y: 0 }, CubePos { x: 3, y: 1 }, CubePos { x: 3, y: 2 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 3, y: 4 }, CubePos { x: 3, y: 5 },
-            CubePos { x: 3, y: 6 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 1, y: 6 }, CubePos { x: 2, y: 6 },
-        ]);
-        
-        // ^ (94)
-        glyphs[62] = Some(vec![
-            CubePos { x: 2, y: 7 }, CubePos { x: 1, y: 6 }, CubePos { x: 3, y: 6 },
-            CubePos { x: 0, y: 5 }, CubePos { x: 4, y: 5 },
-        ]);
-        
-        // _ (95)
-        glyphs[63] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 0 },
-        ]);
-        
-        // ` (96)
-        glyphs[64] = Some(vec![
-            CubePos { x: 1, y: 7 },
-            CubePos { x: 2, y: 6 },
-        ]);
-        
-        // a-z (lowercase) - simplified versions
-        // a (97)
-        glyphs[65] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 2 }, CubePos { x: 4, y: 3 },
-            CubePos { x: 4, y: 4 }, CubePos { x: 0, y: 2 }, CubePos { x: 1, y: 2 },
-            CubePos { x: 2, y: 2 }, CubePos { x: 3, y: 2 }, CubePos { x: 0, y: 4 },
-            CubePos { x: 1, y: 4 }, CubePos { x: 2, y: 4 }, CubePos { x: 3, y: 4 },
-        ]);
-        
-        // b (98)
-        glyphs[66] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 2 },
-            CubePos { x: 4, y: 3 }, CubePos { x: 1, y: 4 }, CubePos { x: 2, y: 4 },
-            CubePos { x: 3, y: 4 },
-        ]);
-        
-        // c (99)
-        glyphs[67] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 }, CubePos { x: 0, y: 3 },
-            CubePos { x: 1, y: 4 }, CubePos { x: 2, y: 4 }, CubePos { x: 3, y: 4 },
-        ]);
-        
-        // d (100)
-        glyphs[68] = Some(vec![
-            CubePos { x: 4, y: 0 }, CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 2 },
-            CubePos { x: 4, y: 3 }, CubePos { x: 4, y: 4 }, CubePos { x: 4, y: 5 },
-            CubePos { x: 4, y: 6 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 1, y: 4 }, CubePos { x: 2, y: 4 },
-            CubePos { x: 3, y: 4 },
-        ]);
-        
-        // e (101)
-        glyphs[69] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 }, CubePos { x: 1, y: 2 },
-            CubePos { x: 2, y: 2 }, CubePos { x: 3, y: 2 }, CubePos { x: 4, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 1, y: 4 }, CubePos { x: 2, y: 4 },
-            CubePos { x: 3, y: 4 },
-        ]);
-        
-        // f (102)
-        glyphs[70] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 1, y: 1 }, CubePos { x: 1, y: 2 },
-            CubePos { x: 1, y: 3 }, CubePos { x: 1, y: 4 }, CubePos { x: 1, y: 5 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 6 }, CubePos { x: 0, y: 3 },
-            CubePos { x: 2, y: 3 },
-        ]);
-        
-        // g (103)
-        glyphs[71] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 2 }, CubePos { x: 4, y: 3 },
-            CubePos { x: 4, y: 4 }, CubePos { x: 0, y: 2 }, CubePos { x: 1, y: 2 },
-            CubePos { x: 2, y: 2 }, CubePos { x: 3, y: 2 }, CubePos { x: 0, y: 4 },
-            CubePos { x: 1, y: 4 }, CubePos { x: 2, y: 4 }, CubePos { x: 3, y: 4 },
-            CubePos { x: 0, y: -1 }, CubePos { x: 1, y: -2 }, CubePos { x: 2, y: -2 },
-            CubePos { x: 3, y: -2 },
-        ]);
-        
-        // h (104)
-        glyphs[72] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 1, y: 2 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 3, y: 2 }, CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 0 },
-        ]);
-        
-        // i (105)
-        glyphs[73] = Some(vec![
-            CubePos { x: 2, y: 0 }, CubePos { x: 2, y: 1 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 2, y: 3 }, CubePos { x: 2, y: 4 }, CubePos { x: 2, y: 6 },
-        ]);
-        
-        // j (106)
-        glyphs[74] = Some(vec![
-            CubePos { x: 3, y: 0 }, CubePos { x: 3, y: 1 }, CubePos { x: 3, y: 2 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 3, y: 4 }, CubePos { x: 3, y: 6 },
-            CubePos { x: 0, y: -1 }, CubePos { x: 1, y: -1 }, CubePos { x: 2, y: -1 },
-        ]);
-        
-        // k (107)
-        glyphs[75] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 0, y: 5 },
-            CubePos { x: 0, y: 6 }, CubePos { x: 3, y: 0 }, CubePos { x: 2, y: 1 },
-            CubePos { x: 1, y: 2 }, CubePos { x: 2, y: 3 }, CubePos { x: 3, y: 4 },
-        ]);
-        
-        // l (108)
-        glyphs[76] = Some(vec![
-            CubePos { x: 2, y: 0 }, CubePos { x: 2, y: 1 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 2, y: 3 }, CubePos { x: 2, y: 4 }, CubePos { x: 2, y: 5 },
-            CubePos { x: 2, y: 6 },
-        ]);
-        
-        // m (109)
-        glyphs[77] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 1, y: 2 },
-            CubePos { x: 2, y: 1 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 2 },
-            CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 0 }, CubePos { x: 6, y: 0 },
-            CubePos { x: 6, y: 1 }, CubePos { x: 6, y: 2 }, CubePos { x: 6, y: 3 },
-            CubePos { x: 6, y: 4 },
-        ]);
-        
-        // n (110)
-        glyphs[78] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 1, y: 2 },
-            CubePos { x: 2, y: 2 }, CubePos { x: 3, y: 2 }, CubePos { x: 4, y: 1 },
-            CubePos { x: 4, y: 0 },
-        ]);
-        
-        // o (111)
-        glyphs[79] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-            CubePos { x: 0, y: 1 }, CubePos { x: 4, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 4, y: 2 }, CubePos { x: 0, y: 3 }, CubePos { x: 4, y: 3 },
-            CubePos { x: 1, y: 4 }, CubePos { x: 2, y: 4 }, CubePos { x: 3, y: 4 },
-        ]);
-        
-        // p (112)
-        glyphs[80] = Some(vec![
-            CubePos { x: 0, y: -2 }, CubePos { x: 0, y: -1 }, CubePos { x: 0, y: 0 },
-            CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 }, CubePos { x: 0, y: 3 },
-            CubePos { x: 0, y: 4 }, CubePos { x: 1, y: 2 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 3, y: 2 }, CubePos { x: 4, y: 3 }, CubePos { x: 4, y: 4 },
-            CubePos { x: 1, y: 4 }, CubePos { x: 2, y: 4 }, CubePos { x: 3, y: 4 },
-        ]);
-        
-        // q (113)
-        glyphs[81] = Some(vec![
-            CubePos { x: 4, y: -2 }, CubePos { x: 4, y: -1 }, CubePos { x: 4, y: 0 },
-            CubePos { x: 4, y: 1 }, CubePos { x: 4, y: 2 }, CubePos { x: 4, y: 3 },
-            CubePos { x: 4, y: 4 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 1, y: 4 }, CubePos { x: 2, y: 4 },
-            CubePos { x: 3, y: 4 },
-        ]);
-        
-        // r (114)
-        glyphs[82] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 1, y: 2 },
-            CubePos { x: 2, y: 3 }, CubePos { x: 3, y: 4 },
-        ]);
-        
-        // s (115)
-        glyphs[83] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 1 }, CubePos { x: 3, y: 2 },
-            CubePos { x: 2, y: 2 }, CubePos { x: 1, y: 2 }, CubePos { x: 0, y: 3 },
-            CubePos { x: 1, y: 4 }, CubePos { x: 2, y: 4 }, CubePos { x: 3, y: 4 },
-            CubePos { x: 4, y: 4 },
-        ]);
-        
-        // t (116)
-        glyphs[84] = Some(vec![
-            CubePos { x: 1, y: 0 }, CubePos { x: 1, y: 1 }, CubePos { x: 1, y: 2 },
-            CubePos { x: 1, y: 3 }, CubePos { x: 1, y: 4 }, CubePos { x: 1, y: 5 },
-            CubePos { x: 0, y: 3 }, CubePos { x: 2, y: 3 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 },
-        ]);
-        
-        // u (117)
-        glyphs[85] = Some(vec![
-            CubePos { x: 0, y: 1 }, CubePos { x: 0, y: 2 }, CubePos { x: 0, y: 3 },
-            CubePos { x: 0, y: 4 }, CubePos { x: 4, y: 0 }, CubePos { x: 4, y: 1 },
-            CubePos { x: 4, y: 2 }, CubePos { x: 4, y: 3 }, CubePos { x: 4, y: 4 },
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 0 },
-        ]);
-        
-        // v (118)
-        glyphs[86] = Some(vec![
-            CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 }, CubePos { x: 1, y: 1 },
-            CubePos { x: 1, y: 2 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 1 },
-            CubePos { x: 3, y: 2 }, CubePos { x: 4, y: 3 }, CubePos { x: 4, y: 4 },
-        ]);
-        
-        // w (119)
-        glyphs[87] = Some(vec![
-            CubePos { x: 0, y: 2 }, CubePos { x: 0, y: 3 }, CubePos { x: 0, y: 4 },
-            CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 1 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 2 }, CubePos { x: 4, y: 3 },
-            CubePos { x: 4, y: 4 },
-        ]);
-        
-        // x (120)
-        glyphs[88] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 1, y: 1 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 4, y: 4 }, CubePos { x: 4, y: 0 },
-            CubePos { x: 3, y: 1 }, CubePos { x: 1, y: 3 }, CubePos { x: 0, y: 4 },
-        ]);
-        
-        // y (121)
-        glyphs[89] = Some(vec![
-            CubePos { x: 0, y: 4 }, CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 2, y: 1 }, CubePos { x: 2, y: 0 }, CubePos { x: 3, y: 3 },
-            CubePos { x: 4, y: 4 }, CubePos { x: 1, y: -1 }, CubePos { x: 0, y: -2 },
-        ]);
-        
-        // z (122)
-        glyphs[90] = Some(vec![
-            CubePos { x: 0, y: 0 }, CubePos { x: 1, y: 0 }, CubePos { x: 2, y: 0 },
-            CubePos { x: 3, y: 0 }, CubePos { x: 4, y: 0 }, CubePos { x: 4, y: 1 },
-            CubePos { x: 3, y: 2 }, CubePos { x: 2, y: 2 }, CubePos { x: 1, y: 3 },
-            CubePos { x: 0, y: 4 }, CubePos { x: 1, y: 4 }, CubePos { x: 2, y: 4 },
-            CubePos { x: 3, y: 4 }, CubePos { x: 4, y: 4 },
-        ]);
-        
-        // { (123)
-        glyphs[91] = Some(vec![
-            CubePos { x: 2, y: 0 }, CubePos { x: 2, y: 1 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 1, y: 3 }, CubePos { x: 2, y: 4 }, CubePos { x: 2, y: 5 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 3, y: 0 }, CubePos { x: 3, y: 6 },
-        ]);
-        
-        // | (124)
-        glyphs[92] = Some(vec![
-            CubePos { x: 2, y: 0 }, CubePos { x: 2, y: 1 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 2, y: 3 }, CubePos { x: 2, y: 4 }, CubePos { x: 2, y: 5 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 2, y: 7 },
-        ]);
-        
-        // } (125)
-        glyphs[93] = Some(vec![
-            CubePos { x: 2, y: 0 }, CubePos { x: 2, y: 1 }, CubePos { x: 2, y: 2 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 2, y: 4 }, CubePos { x: 2, y: 5 },
-            CubePos { x: 2, y: 6 }, CubePos { x: 1, y: 0 }, CubePos { x: 1, y: 6 },
-        ]);
-        
-        // ~ (126)
-        glyphs[94] = Some(vec![
-            CubePos { x: 0, y: 3 }, CubePos { x: 1, y: 4 }, CubePos { x: 2, y: 4 },
-            CubePos { x: 3, y: 3 }, CubePos { x: 4, y: 2 }, CubePos { x: 5, y: 2 },
-            CubePos { x: 6, y: 3 },
-        ]);
+        font_file::parse_grid(font_file::BUILTIN_FONT).expect("built-in TinyText font is well formed")
+    }
+
+    /// A font without glyphs, to be filled with `insert_glyph`. Every character
+    /// is drawn as the default missing-glyph box until then.
+    pub fn empty() -> Self {
+        Self {
+            glyphs: HashMap::new(),
+            missing: Some(Self::missing_box(GLYPH_WIDTH, GLYPH_HEIGHT)),
+            kerning: HashMap::new(),
+        }
+    }
 
-        Self { glyphs }
+    /// Loads a BDF, PSF or grid font file (see `font_file`)
+    pub fn load(path: &std::path::Path) -> Result<Self, FontError> {
+        font_file::load_font(path)
     }
 
+    /// A hollow `width` x `height` rectangle, the usual missing glyph
+    pub fn missing_box(width: i32, height: i32) -> Glyph {
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| CubePos { x, y }))
+            .filter(|cube| cube.x == 0 || cube.y == 0 || cube.x == width - 1 || cube.y == height - 1)
+            .collect()
+    }
+
+    /// Sets the glyph for `c`, returning the glyph it replaces
+    pub fn insert_glyph(&mut self, c: char, glyph: Glyph) -> Option<Glyph> {
+        self.glyphs.insert(c, glyph)
+    }
+
+    /// The glyph defined for `c`, without falling back to the missing glyph
     pub fn get_glyph(&self, c: char) -> Option<&Glyph> {
-        if (' '..='~').contains(&c) {
-            self.glyphs[c as usize - 32].as_ref()
+        self.glyphs.get(&c)
+    }
+
+    /// Sets the glyph drawn for characters the font lacks; `None` leaves them out
+    pub fn set_missing_glyph(&mut self, glyph: Option<Glyph>) {
+        self.missing = glyph;
+    }
+
+    pub fn missing_glyph(&self) -> Option<&Glyph> {
+        self.missing.as_ref()
+    }
+
+    /// The glyph to draw for `c`: its own, or the missing glyph. Control
+    /// characters without a glyph draw nothing.
+    pub fn glyph_or_missing(&self, c: char) -> Option<&Glyph> {
+        match self.glyphs.get(&c) {
+            Some(glyph) => Some(glyph),
+            None if c.is_control() => None,
+            None => self.missing.as_ref(),
+        }
+    }
+
+    /// Metrics of the glyph drawn for `c`; characters that draw nothing take
+    /// no room
+    pub fn metrics(&self, c: char) -> GlyphMetrics {
+        self.glyph_or_missing(c).map_or(GlyphMetrics::default(), |glyph| GlyphMetrics::measure(glyph))
+    }
+
+    /// Sets the extra columns (negative to tighten) between `left` and a
+    /// following `right` in proportional layout; 0 removes the pair
+    pub fn set_kerning(&mut self, left: char, right: char, columns: i32) {
+        if columns == 0 {
+            self.kerning.remove(&(left, right));
         } else {
-            None
+            self.kerning.insert((left, right), columns);
         }
     }
+
+    pub fn kerning(&self, left: char, right: char) -> i32 {
+        self.kerning.get(&(left, right)).copied().unwrap_or(0)
+    }
+
+    /// Number of characters with a glyph of their own
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+}
+
+/// How far the pen moves from one character to the next
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextAdvance {
+    /// Every character moves the pen by the same offset
+    #[default]
+    Fixed,
+    /// Each character takes its measured width plus `PROPORTIONAL_GAP`,
+    /// adjusted by the font's kerning; the offset only adds its Y and Z
+    Proportional,
 }
 
 /// Main TinyText API for 3D cube-based text
 pub struct TinyText;
 
 impl TinyText {
-    /// Spawns a string as a series of 3D cubes (one entity per cube)
-    ///   - commands: Bevy commands for spawning entities
-    ///   - meshes: Mesh assets for cube geometry
-    ///   - materials: Material assets for cube appearance
-    ///   - text: the string to render
-    ///   - origin: world position (Vec3)
-    ///   - scale: Vec3 (cube size and spacing)
-    ///   - orientation: rotation quaternion
-    ///   - offset: Vec3 (per-character offset)
-    ///   - color: cube color
+    /// Each character of `text` with the position its glyph's (0, 0) cube is
+    /// drawn at, starting from `origin`
+    pub fn pen_positions(
+        font: &TinyFont,
+        text: &str,
+        origin: Vec3,
+        scale: Vec3,
+        offset: Vec3,
+        advance: TextAdvance,
+    ) -> Vec<(char, Vec3)> {
+        match advance {
+            TextAdvance::Fixed => text.chars().enumerate().map(|(i, c)| (c, origin + offset * i as f32)).collect(),
+            TextAdvance::Proportional => {
+                let drift = offset * Vec3::new(0.0, 1.0, 1.0);
+                let mut column = 0;
+                let mut previous = None;
+                text.chars()
+                    .enumerate()
+                    .map(|(i, c)| {
+                        let metrics = font.metrics(c);
+                        column += previous.map_or(0, |left| font.kerning(left, c));
+                        let x = (column - metrics.min_x) as f32 * scale.x;
+                        column += metrics.advance;
+                        previous = Some(c);
+                        (c, origin + Vec3::X * x + drift * i as f32)
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Width of `text` from the left edge of its first cube column to the
+    /// right edge of its last, laid out as by `pen_positions`
+    pub fn measure_width(font: &TinyFont, text: &str, scale: Vec3, offset: Vec3, advance: TextAdvance) -> f32 {
+        match advance {
+            TextAdvance::Fixed => Self::line_width(text, scale, offset),
+            TextAdvance::Proportional => {
+                let mut chars = text.chars().peekable();
+                let mut columns = 0;
+                while let Some(c) = chars.next() {
+                    columns += font.metrics(c).advance + chars.peek().map_or(0, |&next| font.kerning(c, next));
+                }
+                (columns - PROPORTIONAL_GAP).max(0) as f32 * scale.x
+            }
+        }
+    }
+
+    /// Spawns a string as cube children of `parent`, laid out in the parent's
+    /// local XY plane so despawning the parent removes the whole text
+    ///   - origin: local position of the first character's bottom-left cube
+    ///   - scale, offset: as for `TinyTextSpawner::spawn_text`
+    ///   - advance: fixed offset or proportional glyph widths
+    #[allow(clippy::too_many_arguments)]
+    pub fn spawn_children(
+        parent: &mut ChildBuilder,
+        font: &TinyFont,
+        cube_mesh: &Handle<Mesh>,
+        material: &Handle<StandardMaterial>,
+        text: &str,
+        origin: Vec3,
+        scale: Vec3,
+        offset: Vec3,
+        advance: TextAdvance,
+    ) {
+        let pens = Self::pen_positions(font, text, origin, scale, offset, advance);
+        Self::spawn_voxels(parent, cube_mesh, material, &TextStyle::default().glyph_voxels(font, &pens), scale);
+    }
+
+    /// Builds a string as one mesh of cubes laid out as by `spawn_children`.
+    /// Faces shared by neighboring cubes of a glyph are left out, so only the
+    /// glyph's outer surface is drawn.
+    pub fn build_mesh(
+        font: &TinyFont,
+        text: &str,
+        origin: Vec3,
+        scale: Vec3,
+        offset: Vec3,
+        advance: TextAdvance,
+    ) -> Mesh {
+        Self::build_mesh_at(font, &Self::pen_positions(font, text, origin, scale, offset, advance), scale)
+    }
+
+    /// Builds one mesh of the glyphs at the given pen positions, as returned by
+    /// `pen_positions` or `TextLayout::pen_positions`
+    pub fn build_mesh_at(font: &TinyFont, pens: &[(char, Vec3)], scale: Vec3) -> Mesh {
+        voxel::build_voxel_mesh(&TextStyle::default().glyph_voxels(font, pens), scale, VoxelShape::Cube)
+    }
+
+    /// Spawns a string as loose cubes in the world, rotated by `orientation`
+    /// about `origin`
+    #[allow(clippy::too_many_arguments)]
+    fn spawn_loose(
+        commands: &mut Commands,
+        font: &TinyFont,
+        cube_mesh: &Handle<Mesh>,
+        material: &Handle<StandardMaterial>,
+        text: &str,
+        origin: Vec3,
+        scale: Vec3,
+        orientation: Quat,
+        offset: Vec3,
+        advance: TextAdvance,
+    ) {
+        for (c, pen) in Self::pen_positions(font, text, Vec3::ZERO, scale, offset, advance) {
+            let Some(glyph) = font.glyph_or_missing(c) else {
+                continue;
+            };
+            for cube_pos in glyph {
+                // Calculate cube position relative to the text origin
+                let local_pos = pen + Vec3::new(cube_pos.x as f32 * scale.x, cube_pos.y as f32 * scale.y, 0.0);
+
+                commands.spawn(PbrBundle {
+                    mesh: cube_mesh.clone(),
+                    material: material.clone(),
+                    transform: Transform {
+                        translation: origin + orientation * local_pos,
+                        rotation: orientation,
+                        scale,
+                    },
+                    ..default()
+                });
+            }
+        }
+    }
+
+    /// Spawns a string as loose cubes with a fresh font, cube mesh and
+    /// material on every call
+    #[deprecated(note = "use TinyTextSpawner::spawn_text, which shares the font, mesh and materials")]
+    #[allow(clippy::too_many_arguments)]
     pub fn spawn_text(
         commands: &mut Commands,
         meshes: &mut ResMut<Assets<Mesh>>,
@@ -858,49 +334,14 @@ impl TinyText {
         offset: Vec3,
         color: Color,
     ) {
+        let cube_mesh = meshes.add(VoxelShape::Cube.mesh());
+        let material = materials.add(TextMaterial::Plain.material(color));
         let font = TinyFont::new();
-        let mut cursor = origin;
-        
-        // Create a cube mesh to reuse
-        let cube_mesh = meshes.add(Mesh::from(Cuboid { half_size: Vec3::splat(0.5) }));
-        let cube_material = materials.add(StandardMaterial {
-            base_color: color,
-            ..default()
-        });
-
-        for c in text.chars() {
-            if let Some(glyph) = font.get_glyph(c) {
-                for cube_pos in glyph {
-                    // Calculate cube position relative to character origin
-                    let local_pos = Vec3::new(
-                        cube_pos.x as f32 * scale.x,
-                        cube_pos.y as f32 * scale.y,
-                        0.0
-                    );
-                    
-                    // Apply orientation and add to cursor position
-                    let world_pos = cursor + orientation * local_pos;
-                    
-                    // Spawn the cube
-                    commands.spawn(PbrBundle {
-                        mesh: cube_mesh.clone(),
-                        material: cube_material.clone(),
-                        transform: Transform {
-                            translation: world_pos,
-                            rotation: orientation,
-                            scale: scale,
-                        },
-                        ..default()
-                    });
-                }
-            }
-            
-            // Advance cursor for next character
-            cursor += orientation * offset;
-        }
+        Self::spawn_loose(commands, &font, &cube_mesh, &material, text, origin, scale, orientation, offset, TextAdvance::Fixed);
     }
-    
-    /// Simplified spawn function with default parameters
+
+    #[deprecated(note = "use TinyTextSpawner::spawn_simple, which shares the font, mesh and materials")]
+    #[allow(deprecated)]
     pub fn spawn_simple(
         commands: &mut Commands,
         meshes: &mut ResMut<Assets<Mesh>>,
@@ -909,20 +350,12 @@ impl TinyText {
         origin: Vec3,
         color: Color,
     ) {
-        Self::spawn_text(
-            commands,
-            meshes,
-            materials,
-            text,
-            origin,
-            Vec3::new(0.2, 0.2, 0.2), // scale
-            Quat::IDENTITY,           // orientation
-            Vec3::new(1.5, 0.0, 0.0), // offset (character spacing)
-            color,
-        );
+        let (scale, offset) = (Vec3::splat(0.2), Vec3::new(1.5, 0.0, 0.0));
+        Self::spawn_text(commands, meshes, materials, text, origin, scale, Quat::IDENTITY, offset, color);
     }
-    
-    /// Spawn text facing the camera
+
+    #[deprecated(note = "use TinyTextSpawner::spawn_billboard, which shares the font, mesh and materials")]
+    #[allow(deprecated)]
     pub fn spawn_billboard(
         commands: &mut Commands,
         meshes: &mut ResMut<Assets<Mesh>>,
@@ -932,13 +365,184 @@ impl TinyText {
         camera_pos: Vec3,
         color: Color,
     ) {
+        let orientation = Quat::from_rotation_arc(Vec3::Z, (camera_pos - origin).normalize());
+        let (scale, offset) = (Vec3::splat(0.2), Vec3::new(1.5, 0.0, 0.0));
+        Self::spawn_text(commands, meshes, materials, text, origin, scale, orientation, offset, color);
+    }
+
+    /// Spawns one child per voxel, each a copy of `mesh` at the voxel's size
+    pub fn spawn_voxels(
+        parent: &mut ChildBuilder,
+        mesh: &Handle<Mesh>,
+        material: &Handle<StandardMaterial>,
+        voxels: &[(Vec3, HashSet<IVec3>)],
+        scale: Vec3,
+    ) {
+        for (pen, cells) in voxels {
+            for &cell in cells {
+                parent.spawn(PbrBundle {
+                    mesh: mesh.clone(),
+                    material: material.clone(),
+                    transform: Transform::from_translation(voxel::cell_center(*pen, cell, scale)).with_scale(scale),
+                    ..default()
+                });
+            }
+        }
+    }
+
+    /// Distance from the left edge of the first character to the right edge of
+    /// the last one, assuming the standard 5-cube glyph width
+    pub fn line_width(text: &str, scale: Vec3, offset: Vec3) -> f32 {
+        match text.chars().count() {
+            0 => 0.0,
+            n => (n - 1) as f32 * offset.x + GLYPH_WIDTH as f32 * scale.x,
+        }
+    }
+
+}
+
+/// Horizontal placement of text relative to its entity
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextAlign {
+    /// The first cube sits on the entity, as with `spawn_text`
+    #[default]
+    Left,
+    Center,
+    /// The text ends at the entity
+    Right,
+}
+
+/// Voxel meshes and per-color materials shared by all TinyText, created on first use
+#[derive(Resource, Default)]
+pub struct TinyTextAssets {
+    voxels: HashMap<VoxelShape, Handle<Mesh>>,
+    /// Keyed by color, then surface kind and glow
+    materials: HashMap<[u32; 6], Handle<StandardMaterial>>,
+}
+
+impl TinyTextAssets {
+    /// The unit cube every glyph cube is scaled from
+    pub fn cube(&mut self, meshes: &mut Assets<Mesh>) -> Handle<Mesh> {
+        self.voxel(meshes, VoxelShape::Cube)
+    }
+
+    /// The unit-sized mesh of a voxel shape
+    pub fn voxel(&mut self, meshes: &mut Assets<Mesh>, shape: VoxelShape) -> Handle<Mesh> {
+        self.voxels.entry(shape).or_insert_with(|| meshes.add(shape.mesh())).clone()
+    }
+
+    /// The plain material for `color`, added once per distinct color
+    pub fn material(&mut self, materials: &mut Assets<StandardMaterial>, color: Color) -> Handle<StandardMaterial> {
+        self.text_material(materials, color, TextMaterial::Plain)
+    }
+
+    /// The material for `color` with the given surface, added once per
+    /// distinct color and surface
+    pub fn text_material(
+        &mut self,
+        materials: &mut Assets<StandardMaterial>,
+        color: Color,
+        surface: TextMaterial,
+    ) -> Handle<StandardMaterial> {
+        let (kind, glow) = match surface {
+            TextMaterial::Plain => (0, 0.0),
+            TextMaterial::Led { glow, .. } => (1, glow),
+        };
+        self.cached(materials, color, [kind, glow.to_bits()], || surface.material(color))
+    }
+
+    /// An unlit material for `color`, used for LED off pixels
+    pub fn unlit_material(&mut self, materials: &mut Assets<StandardMaterial>, color: Color) -> Handle<StandardMaterial> {
+        self.cached(materials, color, [2, 0], || StandardMaterial { base_color: color, unlit: true, ..default() })
+    }
+
+    fn cached(
+        &mut self,
+        materials: &mut Assets<StandardMaterial>,
+        color: Color,
+        surface: [u32; 2],
+        material: impl FnOnce() -> StandardMaterial,
+    ) -> Handle<StandardMaterial> {
+        let [r, g, b, a] = color.as_rgba_f32().map(f32::to_bits);
+        let key = [r, g, b, a, surface[0], surface[1]];
+        if let Some(handle) = self.materials.get(&key) {
+            return handle.clone();
+        }
+        // Before adding a color, let go of those no text uses any more so the
+        // cache only grows with the colors on screen
+        self.materials.retain(|_, handle| match handle {
+            Handle::Strong(handle) => Arc::strong_count(handle) > 1,
+            Handle::Weak(_) => false,
+        });
+        self.materials.entry(key).or_insert_with(|| materials.add(material())).clone()
+    }
+
+    /// Number of distinct materials held
+    pub fn material_count(&self) -> usize {
+        self.materials.len()
+    }
+}
+
+/// Everything needed to spawn TinyText from a system, reusing the shared font,
+/// cube mesh and materials
+#[derive(SystemParam)]
+pub struct TinyTextSpawner<'w, 's> {
+    pub commands: Commands<'w, 's>,
+    pub font: Res<'w, TinyFont>,
+    pub assets: ResMut<'w, TinyTextAssets>,
+    pub meshes: ResMut<'w, Assets<Mesh>>,
+    pub materials: ResMut<'w, Assets<StandardMaterial>>,
+}
+
+impl TinyTextSpawner<'_, '_> {
+    /// Spawns a string as a series of 3D cubes (one entity per cube)
+    ///   - text: the string to render
+    ///   - origin: world position (Vec3)
+    ///   - scale: Vec3 (cube size and spacing)
+    ///   - orientation: rotation quaternion
+    ///   - offset: Vec3 (per-character offset)
+    ///   - color: cube color
+    pub fn spawn_text(&mut self, text: &str, origin: Vec3, scale: Vec3, orientation: Quat, offset: Vec3, color: Color) {
+        self.spawn_text_with(text, origin, scale, orientation, offset, color, TextAdvance::Fixed);
+    }
+
+    /// `spawn_text` with the characters laid out by `advance`; proportional
+    /// text keeps only the vertical and depth parts of `offset`
+    #[allow(clippy::too_many_arguments)]
+    pub fn spawn_text_with(
+        &mut self,
+        text: &str,
+        origin: Vec3,
+        scale: Vec3,
+        orientation: Quat,
+        offset: Vec3,
+        color: Color,
+        advance: TextAdvance,
+    ) {
+        let cube_mesh = self.assets.cube(&mut self.meshes);
+        let material = self.assets.material(&mut self.materials, color);
+        let font = &self.font;
+        TinyText::spawn_loose(&mut self.commands, font, &cube_mesh, &material, text, origin, scale, orientation, offset, advance);
+    }
+
+    /// Simplified spawn function with default parameters
+    pub fn spawn_simple(&mut self, text: &str, origin: Vec3, color: Color) {
+        self.spawn_text(
+            text,
+            origin,
+            Vec3::new(0.2, 0.2, 0.2), // scale
+            Quat::IDENTITY,           // orientation
+            Vec3::new(1.5, 0.0, 0.0), // offset (character spacing)
+            color,
+        );
+    }
+    
+    /// Spawn text facing the camera
+    pub fn spawn_billboard(&mut self, text: &str, origin: Vec3, camera_pos: Vec3, color: Color) {
         let direction = (camera_pos - origin).normalize();
         let orientation = Quat::from_rotation_arc(Vec3::Z, direction);
         
-        Self::spawn_text(
-            commands,
-            meshes,
-            materials,
+        self.spawn_text(
             text,
             origin,
             Vec3::new(0.2, 0.2, 0.2),
@@ -949,13 +553,257 @@ impl TinyText {
     }
 }
 
-// Example usage in a Bevy app:
+/// How `TinyTextPlugin` builds a text's cubes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextMeshMode {
+    /// One child entity per cube
+    #[default]
+    Cubes,
+    /// A single child entity with all cubes merged into one mesh
+    Merged,
+}
+
+/// Vertical placement of text relative to its entity
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VerticalAlign {
+    /// The bottom cube row sits on the entity, as with `spawn_text`
+    #[default]
+    Baseline,
+    Middle,
+    Top,
+}
+
+/// Declarative cube text. `TinyTextPlugin` rebuilds the entity's cube children
+/// whenever this changes, so despawning the entity recursively removes the text.
+#[derive(Component, Clone, Debug)]
+pub struct TinyText3d {
+    pub text: String,
+    /// Cube size
+    pub scale: Vec3,
+    /// Offset from one character to the next
+    pub spacing: Vec3,
+    pub color: Color,
+    pub align: TextAlign,
+    pub vertical_align: VerticalAlign,
+    pub mode: TextMeshMode,
+    /// Fixed `spacing` per character, or proportional glyph widths
+    pub advance: TextAdvance,
+    /// Empty cube rows between lines
+    pub line_spacing: f32,
+    /// Width lines are wrapped at between words
+    pub wrap_width: Option<f32>,
+    /// Box the text is shrunk to fit into
+    pub fit: Option<Vec2>,
+    /// Extrusion, voxel shape and shadow
+    pub style: TextStyle,
+    /// Gradient across the text outside its spans
+    pub fill: TextFill,
+    /// Characters drawn in their own color; later spans win
+    pub spans: Vec<ColorSpan>,
+    pub material: TextMaterial,
+}
+
+impl Default for TinyText3d {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            scale: Vec3::splat(0.2),
+            spacing: Vec3::new(1.5, 0.0, 0.0),
+            color: Color::WHITE,
+            align: TextAlign::default(),
+            vertical_align: VerticalAlign::default(),
+            mode: TextMeshMode::default(),
+            advance: TextAdvance::default(),
+            line_spacing: 3.0,
+            wrap_width: None,
+            fit: None,
+            style: TextStyle::default(),
+            fill: TextFill::default(),
+            spans: Vec::new(),
+            material: TextMaterial::default(),
+        }
+    }
+}
+
+impl TinyText3d {
+    pub fn new(text: impl Into<String>, color: Color) -> Self {
+        Self { text: text.into(), color, ..default() }
+    }
+
+    /// Text made of runs of characters, each in its own color
+    pub fn from_spans<S: AsRef<str>>(runs: impl IntoIterator<Item = (S, Color)>) -> Self {
+        let mut text = Self::default();
+        let mut start = 0;
+        for (run, color) in runs {
+            let end = start + run.as_ref().chars().count();
+            text.text.push_str(run.as_ref());
+            text.spans.push(ColorSpan { start, end, color });
+            start = end;
+        }
+        text
+    }
+
+    pub fn with_scale(mut self, scale: Vec3) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn with_spacing(mut self, spacing: Vec3) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    pub fn with_align(mut self, align: TextAlign, vertical_align: VerticalAlign) -> Self {
+        self.align = align;
+        self.vertical_align = vertical_align;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: TextMeshMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_advance(mut self, advance: TextAdvance) -> Self {
+        self.advance = advance;
+        self
+    }
+
+    pub fn with_line_spacing(mut self, rows: f32) -> Self {
+        self.line_spacing = rows;
+        self
+    }
+
+    pub fn with_wrap_width(mut self, width: f32) -> Self {
+        self.wrap_width = Some(width);
+        self
+    }
+
+    /// Shrinks the cubes, never growing them, until the text fits `size`
+    pub fn with_fit(mut self, size: Vec2) -> Self {
+        self.fit = Some(size);
+        self
+    }
+
+    pub fn with_style(mut self, style: TextStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_fill(mut self, fill: TextFill) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Draws characters `start..end` in `color`
+    pub fn with_span(mut self, start: usize, end: usize, color: Color) -> Self {
+        self.spans.push(ColorSpan { start, end, color });
+        self
+    }
+
+    pub fn with_material(mut self, material: TextMaterial) -> Self {
+        self.material = material;
+        self
+    }
+
+    /// Lines, cube size and bounds of the text in `font`
+    pub fn layout(&self, font: &TinyFont) -> TextLayout {
+        TextLayout::new(font, self)
+    }
+
+    /// Local bounds of the laid out text
+    pub fn measure(&self, font: &TinyFont) -> TextBounds {
+        self.layout(font).bounds
+    }
+
+    /// Width of the widest line
+    pub fn width(&self, font: &TinyFont) -> f32 {
+        self.measure(font).size().x
+    }
+
+    /// Local position of the first line's bottom-left cube for the alignment
+    pub fn origin(&self, font: &TinyFont) -> Vec3 {
+        self.layout(font).lines[0].origin
+    }
+}
+
+/// A `TinyText3d` with the transform and visibility components it needs
+#[derive(Bundle, Clone, Default)]
+pub struct TinyText3dBundle {
+    pub text: TinyText3d,
+    pub spatial: SpatialBundle,
+}
+
+impl TinyText3dBundle {
+    pub fn new(text: TinyText3d, transform: Transform) -> Self {
+        Self { text, spatial: SpatialBundle::from_transform(transform) }
+    }
+}
+
+/// Keeps the cube children of every `TinyText3d` in step with the component
+pub struct TinyTextPlugin;
+
+impl Plugin for TinyTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TinyFont>()
+            .init_resource::<TinyTextAssets>()
+            .add_systems(PostUpdate, rebuild_tiny_text.before(TransformSystem::TransformPropagate));
+    }
+}
+
+/// Replaces the cubes of each added or changed `TinyText3d`
+pub fn rebuild_tiny_text(mut spawner: TinyTextSpawner, texts: Query<(Entity, &TinyText3d), Changed<TinyText3d>>) {
+    if texts.is_empty() {
+        return;
+    }
+    let TinyTextSpawner { commands, font, assets, meshes, materials } = &mut spawner;
+    for (entity, text) in texts.iter() {
+        let mut entity = commands.entity(entity);
+        entity.despawn_descendants();
+        if text.text.trim().is_empty() {
+            continue;
+        }
+        let layout = text.layout(font);
+        let pens = layout.pen_positions(font, text.advance);
+        let style = text.style;
+        let spans = color::span_colors(&text.text, &text.spans);
+        let painter = color::Painter { color: text.color, fill: text.fill, spans: &spans, bounds: layout.bounds };
+        let mut layers: Vec<(GlyphVoxels, Handle<StandardMaterial>)> = painter
+            .color_groups(font, &pens, &style, layout.scale)
+            .into_iter()
+            .map(|(color, voxels)| (voxels, assets.text_material(materials, color, text.material)))
+            .collect();
+        if let Some(shadow) = style.shadow {
+            layers.push((style.shadow_voxels(font, &pens, layout.scale), assets.material(materials, shadow.color)));
+        }
+        if let Some(off) = text.material.off_color() {
+            layers.push((color::off_pixel_voxels(font, &pens, text.advance), assets.unlit_material(materials, off)));
+        }
+        for (voxels, material) in layers {
+            if voxels.iter().all(|(_, cells)| cells.is_empty()) {
+                continue;
+            }
+            match text.mode {
+                TextMeshMode::Cubes => {
+                    let mesh = assets.voxel(meshes, style.voxel);
+                    entity.with_children(|parent| {
+                        TinyText::spawn_voxels(parent, &mesh, &material, &voxels, layout.scale);
+                    });
+                }
+                TextMeshMode::Merged => {
+                    let mesh = meshes.add(voxel::build_voxel_mesh(&voxels, layout.scale, style.voxel));
+                    entity.with_children(|parent| {
+                        parent.spawn(PbrBundle { mesh, material, ..default() });
+                    });
+                }
+            }
+        }
+    }
+}
+
+// Example usage in a Bevy app (with TinyTextPlugin added):
 /*
-fn setup(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
+fn setup(mut commands: Commands, mut text: TinyTextSpawner) {
     // Add camera
     commands.spawn(Camera3dBundle {
         transform: Transform::from_xyz(0.0, 5.0, 15.0)
@@ -975,20 +823,14 @@ fn setup(
     });
     
     // Spawn 3D text
-    TinyText::spawn_simple(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
+    text.spawn_simple(
         "Hello World!",
         Vec3::new(-10.0, 0.0, 0.0),
         Color::rgb(1.0, 0.5, 0.0),
     );
     
     // Spawn rotated text
-    TinyText::spawn_text(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
+    text.spawn_text(
         "Bevy Rocks!",
         Vec3::new(0.0, 3.0, 0.0),
         Vec3::new(0.3, 0.3, 0.3),
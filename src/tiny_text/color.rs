@@ -0,0 +1,185 @@
+//! Coloring TinyText: per-character spans, gradients and LED materials.
+// Voxels are grouped by color and each group is built like a single-color
+// text, so a merged text becomes one mesh per color. Gradients run across the
+// text's laid out bounds in `GRADIENT_STEPS` bands, which bounds the number of
+// materials a gradient creates. Spans override the fill for their characters.
+// Spans count characters of the text, and whitespace (which draws nothing) is
+// skipped when matching them to drawn glyphs, so wrapping does not shift them.
+// The LED material glows with its own color; its optional backplate fills the
+// rest of each character's cell with unlit off pixels.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::layout::TextBounds;
+use super::voxel::{cell_center, GlyphVoxels, TextStyle};
+use super::{TextAdvance, TinyFont, GLYPH_HEIGHT, GLYPH_WIDTH};
+
+/// Distinct colors a gradient is drawn in
+pub const GRADIENT_STEPS: u32 = 16;
+
+/// Characters `start..end` of a text drawn in `color`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorSpan {
+    pub start: usize,
+    pub end: usize,
+    pub color: Color,
+}
+
+/// How the voxels of a text are colored outside its spans
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TextFill {
+    /// Everything in the text's color
+    #[default]
+    Solid,
+    /// From the first color at the left edge to the second at the right
+    Horizontal(Color, Color),
+    /// From the first color at the top edge to the second at the bottom
+    Vertical(Color, Color),
+}
+
+/// Surface the voxels are drawn with
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TextMaterial {
+    /// Lit by the scene like any other object
+    #[default]
+    Plain,
+    /// Lit LEDs glowing at `glow` times their color. With `off`, the rest of
+    /// each character's cell is filled with unlit pixels of that color.
+    Led { glow: f32, off: Option<Color> },
+}
+
+impl TextMaterial {
+    pub fn led(glow: f32) -> Self {
+        TextMaterial::Led { glow, off: None }
+    }
+
+    pub fn with_off_pixels(self, off: Color) -> Self {
+        match self {
+            TextMaterial::Plain => self,
+            TextMaterial::Led { glow, .. } => TextMaterial::Led { glow, off: Some(off) },
+        }
+    }
+
+    /// Color of the unlit backplate pixels, if any
+    pub fn off_color(&self) -> Option<Color> {
+        match self {
+            TextMaterial::Led { off, .. } => *off,
+            TextMaterial::Plain => None,
+        }
+    }
+
+    pub fn material(&self, color: Color) -> StandardMaterial {
+        match *self {
+            TextMaterial::Plain => StandardMaterial { base_color: color, ..default() },
+            TextMaterial::Led { glow, .. } => StandardMaterial { base_color: color, emissive: color * glow, ..default() },
+        }
+    }
+}
+
+/// `from` blended towards `to` at `t`, rounded to one of `GRADIENT_STEPS` bands
+pub fn gradient(from: Color, to: Color, t: f32) -> Color {
+    let steps = (GRADIENT_STEPS - 1) as f32;
+    let t = (t.clamp(0.0, 1.0) * steps).round() / steps;
+    let (from, to) = (Vec4::from(from.as_rgba_f32()), Vec4::from(to.as_rgba_f32()));
+    let [r, g, b, a] = from.lerp(to, t).to_array();
+    Color::rgba(r, g, b, a)
+}
+
+/// Color of each non-whitespace character of `text`, where a span covers it
+pub fn span_colors(text: &str, spans: &[ColorSpan]) -> Vec<Option<Color>> {
+    text.chars()
+        .enumerate()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(index, _)| spans.iter().rev().find(|span| (span.start..span.end).contains(&index)).map(|span| span.color))
+        .collect()
+}
+
+/// Everything needed to pick each voxel's color
+pub struct Painter<'a> {
+    pub color: Color,
+    pub fill: TextFill,
+    /// From `span_colors`
+    pub spans: &'a [Option<Color>],
+    pub bounds: TextBounds,
+}
+
+impl Painter<'_> {
+    fn color_at(&self, span: Option<Color>, position: Vec3) -> Color {
+        if let Some(color) = span {
+            return color;
+        }
+        let size = self.bounds.size().max(Vec2::splat(f32::EPSILON));
+        match self.fill {
+            TextFill::Solid => self.color,
+            TextFill::Horizontal(from, to) => gradient(from, to, (position.x - self.bounds.min.x) / size.x),
+            TextFill::Vertical(from, to) => gradient(from, to, (self.bounds.max.y - position.y) / size.y),
+        }
+    }
+
+    /// The glyph voxels of `style` at the pen positions, split into one group
+    /// per color in order of first use
+    pub fn color_groups(
+        &self,
+        font: &TinyFont,
+        pens: &[(char, Vec3)],
+        style: &TextStyle,
+        scale: Vec3,
+    ) -> Vec<(Color, GlyphVoxels)> {
+        let mut groups: Vec<(Color, GlyphVoxels)> = Vec::new();
+        let mut index_of: HashMap<[u32; 4], usize> = HashMap::new();
+        let mut spans = self.spans.iter().copied();
+        for &(c, pen) in pens {
+            let span = if c.is_whitespace() { None } else { spans.next().flatten() };
+            let Some(glyph) = font.glyph_or_missing(c) else {
+                continue;
+            };
+            let mut by_color: HashMap<[u32; 4], (Color, GlyphVoxels)> = HashMap::new();
+            for cell in style.glyph_cells(glyph) {
+                let color = self.color_at(span, cell_center(pen, cell, scale));
+                let key = color.as_rgba_f32().map(f32::to_bits);
+                let entry = by_color.entry(key).or_insert_with(|| (color, vec![(pen, Default::default())]));
+                entry.1[0].1.insert(cell);
+            }
+            // Keep the group order stable for the same text
+            let mut glyph_groups: Vec<_> = by_color.into_iter().collect();
+            glyph_groups.sort_by_key(|(key, _)| *key);
+            for (key, (color, voxels)) in glyph_groups {
+                let index = *index_of.entry(key).or_insert_with(|| {
+                    groups.push((color, Vec::new()));
+                    groups.len() - 1
+                });
+                groups[index].1.extend(voxels);
+            }
+        }
+        groups
+    }
+}
+
+/// Unlit cells of each character: its cell, widened to cover the glyph, minus
+/// the glyph's own cubes. Proportional text uses the glyph's own columns.
+pub fn off_pixel_voxels(font: &TinyFont, pens: &[(char, Vec3)], advance: TextAdvance) -> GlyphVoxels {
+    pens.iter()
+        .filter_map(|&(c, pen)| {
+            let glyph = font.glyph_or_missing(c)?;
+            let metrics = font.metrics(c);
+            let (columns, rows) = match advance {
+                TextAdvance::Fixed => (0..GLYPH_WIDTH, 0..GLYPH_HEIGHT),
+                TextAdvance::Proportional if metrics.width == 0 => {
+                    (0..metrics.advance - super::PROPORTIONAL_GAP, 0..GLYPH_HEIGHT)
+                }
+                TextAdvance::Proportional => (metrics.min_x..metrics.min_x + metrics.width, 0..GLYPH_HEIGHT),
+            };
+            let min_y = glyph.iter().map(|cube| cube.y).min().unwrap_or(0).min(rows.start);
+            let max_y = glyph.iter().map(|cube| cube.y + 1).max().unwrap_or(0).max(rows.end);
+            let min_x = glyph.iter().map(|cube| cube.x).min().unwrap_or(0).min(columns.start);
+            let max_x = glyph.iter().map(|cube| cube.x + 1).max().unwrap_or(0).max(columns.end);
+            let cells = (min_y..max_y)
+                .flat_map(|y| (min_x..max_x).map(move |x| IVec3::new(x, y, 0)))
+                .filter(|cell| !glyph.iter().any(|cube| cube.x == cell.x && cube.y == cell.y))
+                .collect();
+            Some((pen, cells))
+        })
+        .collect()
+}
@@ -0,0 +1,454 @@
+//! Loading `TinyFont` glyphs from font files.
+// Three formats are read: the ASCII-art grid text format the built-in font
+// ships in (assets/fonts/tiny_font.txt), BDF bitmap fonts and PSF1/PSF2
+// console fonts. Set pixels become cubes with y pointing up; BDF glyphs keep
+// their baseline at y = 0, PSF glyphs (which have no baseline) sit their
+// bottom row there. A grid `glyph missing` or a BDF DEFAULT_CHAR replaces the
+// box drawn for characters the font lacks, and grid `kern` lines fill the
+// kerning table.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use super::{CubePos, Glyph, TinyFont};
+
+/// The built-in font in the grid format
+pub const BUILTIN_FONT: &str = include_str!("../../assets/fonts/tiny_font.txt");
+
+const PSF1_MAGIC: [u8; 2] = [0x36, 0x04];
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+/// Bytes in a PSF2 header; fonts may declare a longer one
+const PSF2_HEADER_SIZE: usize = 32;
+
+/// Errors produced while loading a font file
+#[derive(Debug)]
+pub enum FontError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// A line of a grid or BDF font is malformed
+    Malformed { line: usize, message: String },
+    /// A PSF font has a bad header or is truncated
+    InvalidPsf(String),
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FontError::Io(err) => write!(f, "could not read font: {}", err),
+            FontError::Malformed { line, message } => write!(f, "malformed font at line {}: {}", line, message),
+            FontError::InvalidPsf(message) => write!(f, "invalid PSF font: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for FontError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FontError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for FontError {
+    fn from(err: std::io::Error) -> Self {
+        FontError::Io(err)
+    }
+}
+
+fn malformed(line: usize, message: impl Into<String>) -> FontError {
+    FontError::Malformed { line, message: message.into() }
+}
+
+/// Loads a font, picking the format from the extension: `.bdf`, `.psf` /
+/// `.psfu`, and the grid format for anything else
+pub fn load_font(path: &Path) -> Result<TinyFont, FontError> {
+    let extension = path.extension().and_then(|ext| ext.to_str()).unwrap_or("").to_ascii_lowercase();
+    match extension.as_str() {
+        "bdf" => parse_bdf(&fs::read_to_string(path)?),
+        "psf" | "psfu" => parse_psf(&fs::read(path)?),
+        _ => parse_grid(&fs::read_to_string(path)?),
+    }
+}
+
+/// A grid glyph whose rows are still being read
+struct GridGlyph {
+    /// `None` for the missing glyph
+    character: Option<char>,
+    origin: (i32, i32),
+    rows: Vec<Vec<bool>>,
+}
+
+impl GridGlyph {
+    fn into_glyph(self) -> Glyph {
+        let height = self.rows.len() as i32;
+        let (x0, y0) = self.origin;
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(row, cells)| {
+                let y = y0 + height - 1 - row as i32;
+                cells
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, set)| **set)
+                    .map(move |(column, _)| CubePos { x: x0 + column as i32, y })
+            })
+            .collect()
+    }
+}
+
+/// A glyph's character: the character itself or a `U+XXXX` code point
+fn parse_character(token: &str, line: usize) -> Result<char, FontError> {
+    if let Some(hex) = token.strip_prefix("U+") {
+        return u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| malformed(line, format!("invalid code point {}", token)));
+    }
+    let mut chars = token.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(malformed(line, format!("expected one character or U+XXXX, found {:?}", token))),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(token: &str, what: &str, line: usize) -> Result<T, FontError> {
+    token.parse().map_err(|_| malformed(line, format!("{} must be a whole number, found {:?}", what, token)))
+}
+
+/// Adds a fully read grid glyph, started on `line`, to the font
+fn finish_grid_glyph(
+    glyph: Option<(usize, GridGlyph)>,
+    font: &mut TinyFont,
+    defined: &mut HashSet<Option<char>>,
+) -> Result<(), FontError> {
+    if let Some((line, glyph)) = glyph {
+        if !defined.insert(glyph.character) {
+            let name = glyph.character.map_or("missing".to_string(), |c| format!("{:?}", c));
+            return Err(malformed(line, format!("glyph {} is defined twice", name)));
+        }
+        match glyph.character {
+            Some(c) => {
+                font.insert_glyph(c, glyph.into_glyph());
+            }
+            None => font.set_missing_glyph(Some(glyph.into_glyph())),
+        }
+    }
+    Ok(())
+}
+
+/// Parses the grid text format described in assets/fonts/tiny_font.txt
+pub fn parse_grid(text: &str) -> Result<TinyFont, FontError> {
+    let mut font = TinyFont::empty();
+    let mut defined = HashSet::new();
+    let mut current: Option<(usize, GridGlyph)> = None;
+    for (index, raw) in text.lines().enumerate() {
+        let line = index + 1;
+        let row = raw.trim_end();
+        if row.starts_with("//") {
+            continue;
+        }
+        let mut tokens = row.split_whitespace();
+        let keyword = tokens.next();
+        if keyword == Some("kern") {
+            finish_grid_glyph(current.take(), &mut font, &mut defined)?;
+            let values: Vec<&str> = tokens.collect();
+            let [left, right, columns] = values[..] else {
+                return Err(malformed(line, "expected `kern LEFT RIGHT COLUMNS`"));
+            };
+            let (left, right) = (parse_character(left, line)?, parse_character(right, line)?);
+            font.set_kerning(left, right, parse_number(columns, "kerning", line)?);
+        } else if keyword == Some("glyph") {
+            finish_grid_glyph(current.take(), &mut font, &mut defined)?;
+            let character = match tokens.next().ok_or_else(|| malformed(line, "glyph needs a character"))? {
+                "missing" => None,
+                token => Some(parse_character(token, line)?),
+            };
+            let origin = match (tokens.next(), tokens.next(), tokens.next()) {
+                (None, _, _) => (0, 0),
+                (Some(x), Some(y), None) => (parse_number(x, "x", line)?, parse_number(y, "y", line)?),
+                _ => return Err(malformed(line, "expected `glyph CHAR [X Y]` or `glyph missing [X Y]`")),
+            };
+            current = Some((line, GridGlyph { character, origin, rows: Vec::new() }));
+        } else if row.is_empty() {
+            finish_grid_glyph(current.take(), &mut font, &mut defined)?;
+        } else {
+            let Some((_, glyph)) = current.as_mut() else {
+                return Err(malformed(line, "glyph row outside a glyph; start one with `glyph CHAR`"));
+            };
+            let cells = row
+                .chars()
+                .enumerate()
+                .map(|(column, c)| match c {
+                    '#' => Ok(true),
+                    '.' => Ok(false),
+                    other => Err(malformed(line, format!("unexpected {:?} in column {}; use # or .", other, column + 1))),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(first) = glyph.rows.first().filter(|first| first.len() != cells.len()) {
+                return Err(malformed(
+                    line,
+                    format!("row is {} cells wide but the glyph's first row is {}", cells.len(), first.len()),
+                ));
+            }
+            glyph.rows.push(cells);
+        }
+    }
+    finish_grid_glyph(current.take(), &mut font, &mut defined)?;
+    Ok(font)
+}
+
+/// Bounding box of the BDF glyph being read
+struct BdfBox {
+    width: usize,
+    height: usize,
+    x_offset: i32,
+    y_offset: i32,
+}
+
+/// Parses a BDF (Glyph Bitmap Distribution Format) font. The glyph named by
+/// the DEFAULT_CHAR property, if any, becomes the missing glyph.
+pub fn parse_bdf(text: &str) -> Result<TinyFont, FontError> {
+    let mut font = TinyFont::empty();
+    let mut lines = text.lines().enumerate().map(|(index, line)| (index + 1, line.trim()));
+
+    match lines.find(|(_, line)| !line.is_empty()) {
+        Some((_, line)) if line.starts_with("STARTFONT") => {}
+        Some((line, _)) => return Err(malformed(line, "BDF fonts start with STARTFONT")),
+        None => return Err(malformed(1, "empty font file")),
+    }
+
+    let mut default_char: Option<i64> = None;
+    let mut default_glyph: Option<Glyph> = None;
+    let mut encoding: Option<i64> = None;
+    let mut bounds: Option<BdfBox> = None;
+    while let Some((line, content)) = lines.next() {
+        let mut tokens = content.split_whitespace();
+        match tokens.next() {
+            Some("STARTCHAR") => {
+                encoding = None;
+                bounds = None;
+            }
+            Some("DEFAULT_CHAR") => {
+                let code = tokens.next().ok_or_else(|| malformed(line, "DEFAULT_CHAR needs a code point"))?;
+                default_char = Some(parse_number(code, "DEFAULT_CHAR", line)?);
+            }
+            Some("ENCODING") => {
+                let code = tokens.next().ok_or_else(|| malformed(line, "ENCODING needs a code point"))?;
+                encoding = Some(parse_number(code, "ENCODING", line)?);
+            }
+            Some("BBX") => {
+                let values: Vec<&str> = tokens.collect();
+                let [width, height, x_offset, y_offset] = values[..] else {
+                    return Err(malformed(line, "BBX needs width, height, x offset and y offset"));
+                };
+                bounds = Some(BdfBox {
+                    width: parse_number(width, "BBX width", line)?,
+                    height: parse_number(height, "BBX height", line)?,
+                    x_offset: parse_number(x_offset, "BBX x offset", line)?,
+                    y_offset: parse_number(y_offset, "BBX y offset", line)?,
+                });
+            }
+            Some("BITMAP") => {
+                let bbx = bounds.take().ok_or_else(|| malformed(line, "BITMAP before BBX"))?;
+                let digits = bbx.width.div_ceil(8) * 2;
+                let mut glyph = Glyph::new();
+                for row in 0..bbx.height {
+                    let (line, hex) = lines.next().ok_or_else(|| malformed(line, "font ends inside a BITMAP"))?;
+                    if hex == "ENDCHAR" {
+                        return Err(malformed(line, format!("BITMAP has {} rows, BBX declares {}", row, bbx.height)));
+                    }
+                    if hex.len() < digits || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                        return Err(malformed(line, format!("expected {} hex digits, found {:?}", digits, hex)));
+                    }
+                    let y = bbx.y_offset + (bbx.height - 1 - row) as i32;
+                    for column in 0..bbx.width {
+                        let digit = hex.as_bytes()[column / 4] as char;
+                        let bits = digit.to_digit(16).unwrap_or(0);
+                        if bits & (0x8 >> (column % 4)) != 0 {
+                            glyph.push(CubePos { x: bbx.x_offset + column as i32, y });
+                        }
+                    }
+                }
+                match lines.next() {
+                    Some((_, "ENDCHAR")) => {}
+                    Some((line, _)) => {
+                        return Err(malformed(line, format!("BITMAP has more rows than the {} BBX declares", bbx.height)))
+                    }
+                    None => return Err(malformed(line, "font ends before ENDCHAR")),
+                }
+                if encoding.is_some() && encoding == default_char {
+                    default_glyph = Some(glyph.clone());
+                }
+                // Glyphs without a standard encoding are -1 and are skipped
+                if let Some(c) = encoding.and_then(|code| u32::try_from(code).ok()).and_then(char::from_u32) {
+                    font.insert_glyph(c, glyph);
+                }
+            }
+            _ => {}
+        }
+    }
+    if default_glyph.is_some() {
+        font.set_missing_glyph(default_glyph);
+    }
+    Ok(font)
+}
+
+/// The parts of a PSF1 or PSF2 header the glyphs are read with
+struct PsfHeader {
+    size: usize,
+    count: usize,
+    bytes_per_glyph: usize,
+    width: usize,
+    height: usize,
+    has_table: bool,
+    version: u8,
+}
+
+fn truncated() -> FontError {
+    FontError::InvalidPsf("header is truncated".to_string())
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, FontError> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .ok_or_else(truncated)
+}
+
+fn psf_header(data: &[u8]) -> Result<PsfHeader, FontError> {
+    let header = if data.starts_with(&PSF2_MAGIC) {
+        let header = PsfHeader {
+            size: read_u32(data, 8)? as usize,
+            has_table: read_u32(data, 12)? & 1 != 0,
+            count: read_u32(data, 16)? as usize,
+            bytes_per_glyph: read_u32(data, 20)? as usize,
+            height: read_u32(data, 24)? as usize,
+            width: read_u32(data, 28)? as usize,
+            version: 2,
+        };
+        if header.size < PSF2_HEADER_SIZE {
+            return Err(FontError::InvalidPsf(format!(
+                "header size {} is below the {} bytes of a PSF2 header",
+                header.size, PSF2_HEADER_SIZE
+            )));
+        }
+        header
+    } else if data.starts_with(&PSF1_MAGIC) {
+        let mode = *data.get(2).ok_or_else(truncated)?;
+        let height = *data.get(3).ok_or_else(truncated)? as usize;
+        PsfHeader {
+            size: 4,
+            count: if mode & 0x01 != 0 { 512 } else { 256 },
+            bytes_per_glyph: height,
+            width: 8,
+            height,
+            has_table: mode & 0x06 != 0,
+            version: 1,
+        }
+    } else {
+        return Err(FontError::InvalidPsf("missing PSF1 or PSF2 magic number".to_string()));
+    };
+    if header.width == 0 || header.height == 0 || header.bytes_per_glyph == 0 {
+        return Err(FontError::InvalidPsf(format!(
+            "empty glyphs: {}x{} pixels in {} bytes",
+            header.width, header.height, header.bytes_per_glyph
+        )));
+    }
+    if header.bytes_per_glyph < header.width.div_ceil(8) * header.height {
+        return Err(FontError::InvalidPsf(format!(
+            "{} bytes per glyph is too few for {}x{} pixels",
+            header.bytes_per_glyph, header.width, header.height
+        )));
+    }
+    Ok(header)
+}
+
+/// Parses a PSF1 or PSF2 console font, using its Unicode table when present
+/// and otherwise mapping glyph N to code point N
+pub fn parse_psf(data: &[u8]) -> Result<TinyFont, FontError> {
+    let header = psf_header(data)?;
+    let too_short = || FontError::InvalidPsf(format!("file is too short for {} glyphs", header.count));
+    let glyphs_end = header
+        .count
+        .checked_mul(header.bytes_per_glyph)
+        .and_then(|len| len.checked_add(header.size))
+        .ok_or_else(too_short)?;
+    let glyph_data = data.get(header.size..glyphs_end).ok_or_else(too_short)?;
+    let row_bytes = header.width.div_ceil(8);
+    let glyphs: Vec<Glyph> = glyph_data
+        .chunks(header.bytes_per_glyph)
+        .map(|bitmap| {
+            let mut glyph = Glyph::new();
+            for row in 0..header.height {
+                for column in 0..header.width {
+                    if bitmap[row * row_bytes + column / 8] & (0x80 >> (column % 8)) != 0 {
+                        glyph.push(CubePos { x: column as i32, y: (header.height - 1 - row) as i32 });
+                    }
+                }
+            }
+            glyph
+        })
+        .collect();
+
+    let mut font = TinyFont::empty();
+    if !header.has_table {
+        for (code, glyph) in glyphs.into_iter().enumerate() {
+            if let Some(c) = char::from_u32(code as u32) {
+                font.insert_glyph(c, glyph);
+            }
+        }
+        return Ok(font);
+    }
+    let table = &data[glyphs_end..];
+    let entries = if header.version == 2 { psf2_table(table, header.count)? } else { psf1_table(table, header.count)? };
+    for (glyph, characters) in glyphs.iter().zip(entries) {
+        for c in characters {
+            font.insert_glyph(c, glyph.clone());
+        }
+    }
+    Ok(font)
+}
+
+/// Single code points mapped to each glyph by a PSF1 Unicode table (u16
+/// entries, 0xFFFE starting combining sequences, 0xFFFF ending each glyph)
+fn psf1_table(table: &[u8], count: usize) -> Result<Vec<Vec<char>>, FontError> {
+    let mut values = table.chunks_exact(2).map(|pair| u16::from_le_bytes([pair[0], pair[1]]));
+    (0..count)
+        .map(|index| {
+            let mut characters = Vec::new();
+            let mut in_sequence = false;
+            loop {
+                match values.next() {
+                    Some(0xFFFF) => return Ok(characters),
+                    Some(0xFFFE) => in_sequence = true,
+                    Some(value) if !in_sequence => characters.extend(char::from_u32(value as u32)),
+                    Some(_) => {}
+                    None => return Err(FontError::InvalidPsf(format!("Unicode table ends at glyph {}", index))),
+                }
+            }
+        })
+        .collect()
+}
+
+/// Single code points mapped to each glyph by a PSF2 Unicode table (UTF-8,
+/// 0xFE starting combining sequences, 0xFF ending each glyph)
+fn psf2_table(table: &[u8], count: usize) -> Result<Vec<Vec<char>>, FontError> {
+    let terminators = table.iter().filter(|&&byte| byte == 0xFF).count();
+    if terminators < count {
+        return Err(FontError::InvalidPsf(format!("Unicode table ends at glyph {}", terminators)));
+    }
+    table
+        .split(|&byte| byte == 0xFF)
+        .take(count)
+        .enumerate()
+        .map(|(index, entry)| {
+            let singles = entry.split(|&byte| byte == 0xFE).next().unwrap_or(&[]);
+            std::str::from_utf8(singles)
+                .map(|text| text.chars().collect())
+                .map_err(|_| FontError::InvalidPsf(format!("Unicode table entry {} is not UTF-8", index)))
+        })
+        .collect()
+}
@@ -0,0 +1,175 @@
+//! Multi-line layout of `TinyText3d`: measuring, alignment, wrapping and
+//! shrink-to-fit.
+// Text is split into lines at '\n' and, with a wrap width, greedily at spaces.
+// Each line is aligned on its own; the block of lines is aligned vertically as
+// a whole. Bounds cover the cube edges of the standard glyph cell, so
+// descenders and overhanging punctuation may reach outside them. A fit box
+// shrinks the cubes (never grows them); wrapped text is rewrapped at each
+// size tried, since smaller cubes fit more words on a line.
+
+use bevy::prelude::*;
+
+use super::{TextAdvance, TextAlign, TinyFont, TinyText, TinyText3d, VerticalAlign, GLYPH_HEIGHT};
+
+/// Halvings used to find the largest cube size that fits a wrapped text
+const FIT_STEPS: usize = 16;
+
+/// Axis-aligned rectangle in the text entity's local XY plane
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextBounds {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl TextBounds {
+    pub fn size(&self) -> Vec2 {
+        self.max - self.min
+    }
+
+    pub fn center(&self) -> Vec2 {
+        (self.min + self.max) / 2.0
+    }
+}
+
+/// One line of laid out text
+#[derive(Clone, Debug, PartialEq)]
+pub struct LaidOutLine {
+    pub text: String,
+    /// Local position of the line's first bottom-left cube
+    pub origin: Vec3,
+    pub width: f32,
+}
+
+/// Where every line of a `TinyText3d` goes, and the cube size it is drawn at
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextLayout {
+    pub lines: Vec<LaidOutLine>,
+    /// Cube size after any shrinking to fit
+    pub scale: Vec3,
+    /// Character offset after any shrinking to fit
+    pub spacing: Vec3,
+    pub bounds: TextBounds,
+}
+
+impl TextLayout {
+    pub fn new(font: &TinyFont, text: &TinyText3d) -> Self {
+        let layout = Self::at_factor(font, text, 1.0);
+        let Some(fit) = text.fit else {
+            return layout;
+        };
+        let fits = |layout: &Self| {
+            let size = layout.bounds.size();
+            size.x <= fit.x + 1e-4 && size.y <= fit.y + 1e-4
+        };
+        if fits(&layout) {
+            return layout;
+        }
+        if text.wrap_width.is_none() {
+            // Without wrapping the lines stay put, so the size scales linearly
+            let size = layout.bounds.size();
+            let factor = (fit / size).min_element();
+            return Self::at_factor(font, text, factor);
+        }
+        // Smaller cubes fit more words on a line, so search for the largest
+        // size whose rewrapped lines fit
+        let (mut low, mut high) = (0.0, 1.0);
+        let mut best = None;
+        for _ in 0..FIT_STEPS {
+            let factor = (low + high) / 2.0;
+            let candidate = Self::at_factor(font, text, factor);
+            if fits(&candidate) {
+                low = factor;
+                best = Some(candidate);
+            } else {
+                high = factor;
+            }
+        }
+        best.unwrap_or_else(|| Self::at_factor(font, text, low.max(f32::EPSILON)))
+    }
+
+    /// Wraps and places the text with its cubes and spacing scaled by `factor`
+    fn at_factor(font: &TinyFont, text: &TinyText3d, factor: f32) -> Self {
+        let (scale, spacing) = (text.scale * factor, text.spacing * factor);
+        let lines = wrap_lines(font, &text.text, scale, spacing, text.advance, text.wrap_width);
+        Self::place(font, text, lines, scale, spacing)
+    }
+
+    /// Aligns already wrapped lines at one cube size
+    fn place(font: &TinyFont, text: &TinyText3d, lines: Vec<String>, scale: Vec3, spacing: Vec3) -> Self {
+        let line_height = GLYPH_HEIGHT as f32 * scale.y;
+        let pitch = line_height + text.line_spacing * scale.y;
+        let height = lines.len() as f32 * pitch - text.line_spacing * scale.y;
+        // Top edge of the first line; a single baseline-aligned line keeps its
+        // bottom cube centers on the entity
+        let top = match text.vertical_align {
+            VerticalAlign::Baseline => line_height - scale.y / 2.0,
+            VerticalAlign::Middle => height / 2.0,
+            VerticalAlign::Top => 0.0,
+        };
+
+        let mut min_x = f32::INFINITY;
+        let mut max_x = f32::NEG_INFINITY;
+        let lines: Vec<LaidOutLine> = lines
+            .into_iter()
+            .enumerate()
+            .map(|(row, line)| {
+                let width = TinyText::measure_width(font, &line, scale, spacing, text.advance);
+                // Cube centers sit half a cube inside the line's edges
+                let left = match text.align {
+                    TextAlign::Left => -scale.x / 2.0,
+                    TextAlign::Center => -width / 2.0,
+                    TextAlign::Right => -width,
+                };
+                min_x = min_x.min(left);
+                max_x = max_x.max(left + width);
+                let bottom = top - row as f32 * pitch - line_height;
+                LaidOutLine { text: line, origin: Vec3::new(left + scale.x / 2.0, bottom + scale.y / 2.0, 0.0), width }
+            })
+            .collect();
+        let bounds = TextBounds { min: Vec2::new(min_x, top - height), max: Vec2::new(max_x, top) };
+        Self { lines, scale, spacing, bounds }
+    }
+
+    /// Each character of every line with the position its glyph's (0, 0) cube
+    /// is drawn at
+    pub fn pen_positions(&self, font: &TinyFont, advance: TextAdvance) -> Vec<(char, Vec3)> {
+        self.lines
+            .iter()
+            .flat_map(|line| TinyText::pen_positions(font, &line.text, line.origin, self.scale, self.spacing, advance))
+            .collect()
+    }
+}
+
+/// Splits `text` into lines at '\n' and, given `max_width`, between words so
+/// each line fits. A word wider than `max_width` gets a line of its own.
+/// Wrapped lines have their runs of spaces collapsed.
+pub fn wrap_lines(
+    font: &TinyFont,
+    text: &str,
+    scale: Vec3,
+    spacing: Vec3,
+    advance: TextAdvance,
+    max_width: Option<f32>,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n').map(|line| line.trim_end_matches('\r')) {
+        let Some(max_width) = max_width else {
+            lines.push(paragraph.to_string());
+            continue;
+        };
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if !line.is_empty() {
+                let candidate = format!("{} {}", line, word);
+                if TinyText::measure_width(font, &candidate, scale, spacing, advance) <= max_width {
+                    line = candidate;
+                    continue;
+                }
+                lines.push(std::mem::take(&mut line));
+            }
+            line.push_str(word);
+        }
+        lines.push(line);
+    }
+    lines
+}
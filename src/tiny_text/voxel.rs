@@ -0,0 +1,289 @@
+//! Glyph styles: extruded layers, voxel shapes and a shadow or outline layer.
+// A glyph's 2D `CubePos` cells become 3D voxel cells: `depth` layers extruded
+// backwards from the front face, so text stays where a flat glyph would be.
+// Voxels are drawn as cubes, dot-matrix spheres or beveled cubes. An optional
+// shadow layer sits directly behind the glyph in a second color, moved by its
+// offset and, for an outline, grown by one cell all round. Merged cube meshes
+// leave out every face shared by two cells of a glyph; other shapes are copied
+// whole per cell.
+
+use std::collections::HashSet;
+
+use bevy::math::primitives::{Cuboid, Sphere};
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology, VertexAttributeValues};
+use bevy::render::render_asset::RenderAssetUsages;
+
+use super::{CubePos, TinyFont};
+
+/// Radius of a dot-matrix sphere in cubes; the gap between dots reads as LEDs
+const SPHERE_RADIUS: f32 = 0.45;
+/// Width of a beveled cube's chamfer in cubes
+const BEVEL: f32 = 0.12;
+
+/// Voxel cells of each glyph, next to the position their (0, 0, 0) cell is
+/// drawn at
+pub type GlyphVoxels = Vec<(Vec3, HashSet<IVec3>)>;
+
+/// Shape each cell of a glyph is drawn as
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum VoxelShape {
+    #[default]
+    Cube,
+    /// Spheres with gaps between them, like a dot-matrix display
+    Sphere,
+    /// Cubes with chamfered edges and corners
+    Beveled,
+}
+
+impl VoxelShape {
+    /// The shape at unit cube size, centered on the origin
+    pub fn mesh(self) -> Mesh {
+        match self {
+            VoxelShape::Cube => Cuboid::new(1.0, 1.0, 1.0).into(),
+            VoxelShape::Sphere => Sphere::new(SPHERE_RADIUS).mesh().uv(12, 8),
+            VoxelShape::Beveled => beveled_cube(BEVEL),
+        }
+    }
+}
+
+/// A copy of the glyphs in a second color behind the text
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextShadow {
+    pub color: Color,
+    /// Offset from directly behind the glyph, in cubes
+    pub offset: Vec3,
+    /// Grows the copy by one cell all round
+    pub outline: bool,
+}
+
+impl TextShadow {
+    /// A shadow one cube right of and below the glyph
+    pub fn drop(color: Color) -> Self {
+        Self { color, offset: Vec3::new(1.0, -1.0, 0.0), outline: false }
+    }
+
+    /// A one-cube rim around the glyph
+    pub fn outline(color: Color) -> Self {
+        Self { color, offset: Vec3::ZERO, outline: true }
+    }
+}
+
+/// How the cells of a glyph are built up
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextStyle {
+    /// Layers of cubes extruded backwards from the front face
+    pub depth: u32,
+    pub voxel: VoxelShape,
+    pub shadow: Option<TextShadow>,
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        Self { depth: 1, voxel: VoxelShape::default(), shadow: None }
+    }
+}
+
+impl TextStyle {
+    pub fn with_depth(mut self, depth: u32) -> Self {
+        self.depth = depth.max(1);
+        self
+    }
+
+    pub fn with_voxel(mut self, voxel: VoxelShape) -> Self {
+        self.voxel = voxel;
+        self
+    }
+
+    pub fn with_shadow(mut self, shadow: TextShadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// The cells of `glyph` in every extruded layer
+    pub fn glyph_cells(&self, glyph: &[CubePos]) -> HashSet<IVec3> {
+        (0..self.depth.max(1) as i32)
+            .flat_map(|layer| glyph.iter().map(move |cube| IVec3::new(cube.x, cube.y, -layer)))
+            .collect()
+    }
+
+    /// The cells of the shadow layer of `glyph`, grown for an outline
+    pub fn shadow_cells(&self, glyph: &[CubePos]) -> HashSet<IVec3> {
+        let grow = match self.shadow {
+            Some(TextShadow { outline: true, .. }) => 1,
+            Some(_) => 0,
+            None => return HashSet::new(),
+        };
+        glyph
+            .iter()
+            .flat_map(|cube| {
+                (-grow..=grow).flat_map(move |dx| (-grow..=grow).map(move |dy| IVec3::new(cube.x + dx, cube.y + dy, 0)))
+            })
+            .collect()
+    }
+
+    /// Voxels drawn in the text color for glyphs at the given pen positions
+    pub fn glyph_voxels(&self, font: &TinyFont, pens: &[(char, Vec3)]) -> GlyphVoxels {
+        pens.iter()
+            .filter_map(|&(c, pen)| font.glyph_or_missing(c).map(|glyph| (pen, self.glyph_cells(glyph))))
+            .collect()
+    }
+
+    /// Voxels of the shadow layer, directly behind the glyphs' back layer and
+    /// moved by the shadow's offset; empty without a shadow
+    pub fn shadow_voxels(&self, font: &TinyFont, pens: &[(char, Vec3)], scale: Vec3) -> GlyphVoxels {
+        let Some(shadow) = self.shadow else {
+            return Vec::new();
+        };
+        let behind = (shadow.offset - Vec3::Z * self.depth.max(1) as f32) * scale;
+        pens.iter()
+            .filter_map(|&(c, pen)| font.glyph_or_missing(c).map(|glyph| (pen + behind, self.shadow_cells(glyph))))
+            .collect()
+    }
+}
+
+/// Center of `cell` of a glyph drawn at `pen`
+pub fn cell_center(pen: Vec3, cell: IVec3, scale: Vec3) -> Vec3 {
+    pen + cell.as_vec3() * scale
+}
+
+/// Builds one mesh of voxels. Cube faces shared by two cells of a glyph are
+/// left out, so only each glyph's outer surface is drawn.
+pub fn build_voxel_mesh(voxels: &[(Vec3, HashSet<IVec3>)], scale: Vec3, shape: VoxelShape) -> Mesh {
+    let mut builder = MeshBuilder::default();
+    if shape == VoxelShape::Cube {
+        // Outward normal and two in-face axes whose cross product is the normal
+        const FACES: [(IVec3, Vec3, Vec3); 6] = [
+            (IVec3::X, Vec3::Y, Vec3::Z),
+            (IVec3::NEG_X, Vec3::Z, Vec3::Y),
+            (IVec3::Y, Vec3::Z, Vec3::X),
+            (IVec3::NEG_Y, Vec3::X, Vec3::Z),
+            (IVec3::Z, Vec3::X, Vec3::Y),
+            (IVec3::NEG_Z, Vec3::Y, Vec3::X),
+        ];
+        let half = scale / 2.0;
+        for (pen, cells) in voxels {
+            for &cell in cells {
+                let center = cell_center(*pen, cell, scale);
+                for (normal, a, b) in FACES {
+                    if cells.contains(&(cell + normal)) {
+                        continue;
+                    }
+                    let n = normal.as_vec3();
+                    let corners = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)];
+                    builder.quad(corners.map(|(sa, sb)| center + (n + a * sa + b * sb) * half), n);
+                }
+            }
+        }
+    } else {
+        let unit = shape.mesh();
+        for (pen, cells) in voxels {
+            for &cell in cells {
+                builder.append(&unit, cell_center(*pen, cell, scale), scale);
+            }
+        }
+    }
+    builder.finish()
+}
+
+/// Vertex data of a mesh being put together
+#[derive(Default)]
+struct MeshBuilder {
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    uvs: Vec<[f32; 2]>,
+    indices: Vec<u32>,
+}
+
+impl MeshBuilder {
+    /// Adds a quad whose corners run counterclockwise seen from `normal`
+    fn quad(&mut self, corners: [Vec3; 4], normal: Vec3) {
+        let base = self.positions.len() as u32;
+        for (corner, uv) in corners.iter().zip([[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]]) {
+            self.positions.push(corner.to_array());
+            self.normals.push(normal.to_array());
+            self.uvs.push(uv);
+        }
+        self.indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+    }
+
+    /// Adds a flat convex polygon facing `normal`, in any corner order
+    fn polygon(&mut self, corners: &[Vec3], normal: Vec3) {
+        let center = corners.iter().copied().sum::<Vec3>() / corners.len() as f32;
+        let u = normal.any_orthonormal_vector();
+        let v = normal.cross(u);
+        let mut corners = corners.to_vec();
+        // Sorting by angle around `normal` winds them counterclockwise
+        corners.sort_by(|a, b| {
+            let angle = |p: &Vec3| (*p - center).dot(v).atan2((*p - center).dot(u));
+            angle(a).total_cmp(&angle(b))
+        });
+        let base = self.positions.len() as u32;
+        for corner in &corners {
+            self.positions.push(corner.to_array());
+            self.normals.push(normal.to_array());
+            self.uvs.push([corner.dot(u) + 0.5, corner.dot(v) + 0.5]);
+        }
+        for i in 1..corners.len() as u32 - 1 {
+            self.indices.extend([base, base + i, base + i + 1]);
+        }
+    }
+
+    /// Adds a copy of `mesh` scaled by `scale` and moved to `center`
+    fn append(&mut self, mesh: &Mesh, center: Vec3, scale: Vec3) {
+        let base = self.positions.len() as u32;
+        if let Some(VertexAttributeValues::Float32x3(positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            self.positions.extend(positions.iter().map(|p| (center + Vec3::from(*p) * scale).to_array()));
+        }
+        if let Some(VertexAttributeValues::Float32x3(normals)) = mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+            self.normals.extend(normals.iter().map(|n| (Vec3::from(*n) / scale).normalize_or_zero().to_array()));
+        }
+        if let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+            self.uvs.extend(uvs);
+        }
+        if let Some(indices) = mesh.indices() {
+            self.indices.extend(indices.iter().map(|i| base + i as u32));
+        }
+    }
+
+    fn finish(self) -> Mesh {
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, self.positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, self.normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, self.uvs);
+        mesh.insert_indices(Indices::U32(self.indices));
+        mesh
+    }
+}
+
+/// A unit cube with its edges and corners cut off `bevel` deep: 6 faces, 12
+/// edge strips and 8 corner triangles, each flat shaded
+fn beveled_cube(bevel: f32) -> Mesh {
+    let (h, i) = (0.5, 0.5 - bevel);
+    let axes = [Vec3::X, Vec3::Y, Vec3::Z];
+    let signs = [-1.0, 1.0];
+    let mut builder = MeshBuilder::default();
+    for (k, &axis) in axes.iter().enumerate() {
+        let (a, b) = (axes[(k + 1) % 3], axes[(k + 2) % 3]);
+        for s in signs {
+            let n = axis * s;
+            builder.polygon(&[n * h + (a + b) * i, n * h + (a - b) * i, n * h - (a + b) * i, n * h - (a - b) * i], n);
+            // The edge strips between this face and the next axis' faces
+            for t in signs {
+                let m = a * t;
+                let corners = [n * h + m * i + b * i, n * h + m * i - b * i, m * h + n * i + b * i, m * h + n * i - b * i];
+                builder.polygon(&corners, (n + m).normalize());
+            }
+        }
+    }
+    for x in signs {
+        for y in signs {
+            for z in signs {
+                let corner = Vec3::new(x, y, z);
+                let points = [Vec3::new(x * h, y * i, z * i), Vec3::new(x * i, y * h, z * i), Vec3::new(x * i, y * i, z * h)];
+                builder.polygon(&points, corner.normalize());
+            }
+        }
+    }
+    builder.finish()
+}
@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+use std::f32::consts::PI;
+
+use bevy::render::mesh::{Mesh, VertexAttributeValues};
+use pi_vs_pi::pi_vs_pi::chart::{
+    angular_share, create_pie_slice_mesh, layout_chart, radial_share, slice_angles, ChartSpec, MeshQuality,
+    SliceLayout,
+};
+use pi_vs_pi::pi_vs_pi::radius::{RadiusConfig, RadiusStrategy};
+use pi_vs_pi::pi_vs_pi::CompetitionData;
+
+fn categories(rows: &[[f32; 2]]) -> Vec<CompetitionData> {
+    rows.iter()
+        .enumerate()
+        .map(|(i, values)| CompetitionData::new(format!("C{}", i), values.to_vec()))
+        .collect()
+}
+
+fn index_count(mesh: &Mesh) -> usize {
+    mesh.indices().map_or(0, |indices| indices.len())
+}
+
+fn vec3s(mesh: &Mesh, attribute: bevy::render::mesh::MeshVertexAttribute) -> Vec<[f32; 3]> {
+    match mesh.attribute(attribute) {
+        Some(VertexAttributeValues::Float32x3(values)) => values.clone(),
+        other => panic!("expected Float32x3 values, found {:?}", other),
+    }
+}
+
+fn triangles(mesh: &Mesh) -> Vec<[usize; 3]> {
+    let indices: Vec<usize> = mesh.indices().expect("mesh has indices").iter().collect();
+    indices.chunks(3).map(|t| [t[0], t[1], t[2]]).collect()
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn wedge(start_angle: f32, end_angle: f32, segments: u32) -> Mesh {
+    let slice = SliceLayout { category_index: 0, start_angle, end_angle, radius: 4.0 };
+    // A vanishing tolerance makes the segment cap the exact count
+    let spec = ChartSpec { max_chord_error: 1e-9, max_segments: segments, height: 2.0, ..ChartSpec::default() };
+    create_pie_slice_mesh(&slice, &spec)
+}
+
+#[test]
+fn spans_cover_the_full_circle_in_proportion() {
+    let data = categories(&[[1.0, 0.0], [3.0, 0.0]]);
+    let spans = slice_angles(&data, 0);
+    assert_eq!(spans[0], (0.0, PI / 2.0));
+    assert!((spans[1].1 - 2.0 * PI).abs() < 1e-5);
+}
+
+#[test]
+fn zero_entity_total_splits_the_chart_evenly() {
+    let data = categories(&[[0.0, 1.0], [0.0, 2.0], [0.0, 3.0], [0.0, 4.0]]);
+    for (i, (start, end)) in slice_angles(&data, 0).into_iter().enumerate() {
+        assert!((start - i as f32 * PI / 2.0).abs() < 1e-5);
+        assert!((end - start - PI / 2.0).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn shares_fall_back_to_an_even_split_for_zero_totals() {
+    let data = categories(&[[0.0, 3.0], [0.0, 1.0], [0.0, 0.0]]);
+    assert_eq!(angular_share(&data, 0, 1), 0.75);
+    assert!((angular_share(&data, 2, 0) - 1.0 / 3.0).abs() < 1e-6);
+    assert_eq!(radial_share(&data[0], 1), 1.0);
+    assert_eq!(radial_share(&data[2], 0), 0.5);
+}
+
+#[test]
+fn zero_value_slice_is_empty_and_has_no_geometry() {
+    let data = categories(&[[0.0, 5.0], [4.0, 5.0]]);
+    let spec = ChartSpec::default();
+    let slices = layout_chart(&data, 0, &RadiusConfig::default(), &spec);
+
+    assert_eq!(slices[0].start_angle, slices[0].end_angle);
+    assert_eq!(slices[0].radius, 0.0);
+    assert!(slices[0].is_empty());
+    let mesh = create_pie_slice_mesh(&slices[0], &spec);
+    assert_eq!(mesh.count_vertices(), 0);
+    assert_eq!(index_count(&mesh), 0);
+
+    assert!(!slices[1].is_empty());
+    assert!(index_count(&create_pie_slice_mesh(&slices[1], &spec)) > 0);
+}
+
+#[test]
+fn zero_category_total_counts_as_an_even_split() {
+    let data = categories(&[[0.0, 0.0], [2.0, 6.0]]);
+    let spec = ChartSpec { base_radius: 10.0, ..ChartSpec::default() };
+    let slices = layout_chart(&data, 1, &RadiusConfig::default(), &spec);
+    assert_eq!(slices[0].radius, 5.0);
+    assert_eq!(slices[1].radius, 7.5);
+}
+
+#[test]
+fn all_zero_dataset_produces_finite_slices() {
+    let data = categories(&[[0.0, 0.0], [0.0, 0.0]]);
+    let radius = RadiusConfig { strategy: RadiusStrategy::AreaPreserving, min_fraction: 0.0 };
+    let slices = layout_chart(&data, 0, &radius, &ChartSpec::default());
+    for slice in &slices {
+        assert!(slice.radius.is_finite() && slice.radius > 0.0);
+        assert!(!slice.is_empty());
+    }
+}
+
+#[test]
+fn segment_count_follows_arc_length() {
+    let spec = ChartSpec::default();
+    assert!(spec.segment_count(2.0, 9.0) > spec.segment_count(0.5, 9.0));
+    assert!(spec.segment_count(1.0, 9.0) > spec.segment_count(1.0, 1.0));
+    assert_eq!(spec.segment_count(0.01, 9.0), 1);
+    // Even a loose tolerance never spans more than a quarter turn per segment
+    let coarse = ChartSpec { max_chord_error: 100.0, ..ChartSpec::default() };
+    assert_eq!(coarse.segment_count(2.0 * PI, 9.0), 4);
+}
+
+#[test]
+fn segments_stay_within_the_chord_error() {
+    let spec = ChartSpec { max_segments: u32::MAX, ..ChartSpec::default() };
+    for (quality, scale) in [(MeshQuality::Low, 4.0), (MeshQuality::Medium, 1.0), (MeshQuality::High, 0.25)] {
+        let spec = ChartSpec { quality, ..spec.clone() };
+        for (span, radius) in [(0.2, 2.0), (1.0, 9.0), (2.0 * PI, 9.0), (3.0, 0.5)] {
+            let segments = spec.segment_count(span, radius);
+            let sagitta = radius * (1.0 - (span / segments as f32 / 2.0).cos());
+            assert!(sagitta <= spec.max_chord_error * scale + 1e-5);
+        }
+    }
+}
+
+#[test]
+fn quality_scales_segment_counts() {
+    let count = |quality| ChartSpec { quality, ..ChartSpec::default() }.segment_count(PI, 9.0);
+    assert!(count(MeshQuality::Low) < count(MeshQuality::Medium));
+    assert!(count(MeshQuality::Medium) < count(MeshQuality::High));
+    let capped = ChartSpec { max_segments: 3, ..ChartSpec::default() };
+    assert_eq!(capped.segment_count(PI, 9.0), 3);
+}
+
+#[test]
+fn thousand_category_chart_uses_one_segment_per_slice() {
+    let data: Vec<CompetitionData> =
+        (0..1000).map(|i| CompetitionData::new(format!("C{}", i), vec![1.0 + (i % 7) as f32, 2.0])).collect();
+    let spec = ChartSpec::default();
+    let segments: u32 = layout_chart(&data, 0, &RadiusConfig::default(), &spec)
+        .iter()
+        .map(|slice| spec.segment_count(slice.end_angle - slice.start_angle, slice.radius))
+        .sum();
+    assert_eq!(segments, 1000);
+}
+
+#[test]
+fn wedge_has_separate_vertices_for_every_face() {
+    for segments in [1, 5, 32] {
+        let mesh = wedge(0.3, 1.9, segments);
+        let n = segments as usize;
+        // Two caps of (center + n + 1), the curved wall 2(n + 1), two radial cuts of 4
+        assert_eq!(mesh.count_vertices(), 4 * n + 14);
+        // Caps n each, wall 2n, radial cuts 2 each
+        assert_eq!(index_count(&mesh), 3 * (4 * n + 4));
+        match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+            Some(VertexAttributeValues::Float32x2(uvs)) => {
+                assert_eq!(uvs.len(), mesh.count_vertices());
+                assert!(uvs.iter().flatten().all(|c| (0.0..=1.0).contains(c)));
+            }
+            other => panic!("expected UVs, found {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn wedge_normals_point_outward() {
+    let (start, end) = (0.3, 1.9);
+    let mesh = wedge(start, end, 8);
+    let positions = vec3s(&mesh, Mesh::ATTRIBUTE_POSITION);
+    let normals = vec3s(&mesh, Mesh::ATTRIBUTE_NORMAL);
+    let start_out = [start.sin(), -start.cos(), 0.0];
+    let end_out = [-end.sin(), end.cos(), 0.0];
+
+    let (mut caps, mut wall, mut cuts) = (0, 0, 0);
+    for (p, n) in positions.iter().zip(&normals) {
+        assert!((dot(*n, *n) - 1.0).abs() < 1e-5, "normal {:?} is not unit length", n);
+        if n[2].abs() > 0.5 {
+            // Front and back caps face away from the chart's mid-plane
+            assert_eq!(n[2].signum(), p[2].signum());
+            caps += 1;
+        } else if dot(*n, start_out) > 0.999 || dot(*n, end_out) > 0.999 {
+            cuts += 1;
+        } else {
+            // Curved wall normals are radial
+            let radial = [p[0] / 4.0, p[1] / 4.0, 0.0];
+            assert!(dot(*n, radial) > 0.999, "wall normal {:?} at {:?} is not radial", n, p);
+            wall += 1;
+        }
+    }
+    assert_eq!((caps, wall, cuts), (2 * 10, 2 * 9, 8));
+
+    // Triangle winding agrees with the vertex normals
+    for [a, b, c] in triangles(&mesh) {
+        let face = cross(sub(positions[b], positions[a]), sub(positions[c], positions[a]));
+        for v in [a, b, c] {
+            assert!(dot(face, normals[v]) > 0.0, "triangle {:?} winds against its normals", [a, b, c]);
+        }
+    }
+}
+
+#[test]
+fn wedge_is_watertight() {
+    let mesh = wedge(0.0, 2.5, 6);
+    let positions = vec3s(&mesh, Mesh::ATTRIBUTE_POSITION);
+    // Weld coincident vertices, then every edge must be shared by exactly two triangles
+    let key = |p: [f32; 3]| p.map(|c| (c * 1e4).round() as i64);
+    let mut welded = HashMap::new();
+    let ids: Vec<usize> = positions
+        .iter()
+        .map(|&p| {
+            let next = welded.len();
+            *welded.entry(key(p)).or_insert(next)
+        })
+        .collect();
+
+    let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+    for [a, b, c] in triangles(&mesh) {
+        for (u, v) in [(a, b), (b, c), (c, a)] {
+            let (u, v) = (ids[u], ids[v]);
+            *edges.entry((u.min(v), u.max(v))).or_default() += 1;
+        }
+    }
+    assert!(edges.values().all(|&count| count == 2), "open or non-manifold edges: {:?}", edges);
+}
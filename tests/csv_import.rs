@@ -0,0 +1,80 @@
+use pi_vs_pi::pi_vs_pi::csv_import::{parse_csv_dataset, ColumnRef, CsvMapping, HeaderMode};
+use pi_vs_pi::pi_vs_pi::dataset::DatasetError;
+use pi_vs_pi::pi_vs_pi::Dataset;
+
+fn parse(csv: &str, header: HeaderMode) -> Result<Dataset, DatasetError> {
+    parse_csv_dataset(csv, &CsvMapping { header, ..CsvMapping::default() })
+}
+
+fn names(dataset: &Dataset) -> (Vec<&str>, Vec<&str>) {
+    (
+        dataset.entities.iter().map(|entity| entity.name.as_str()).collect(),
+        dataset.categories.iter().map(|category| category.name.as_str()).collect(),
+    )
+}
+
+/// Line and column of a CSV error
+fn position(result: Result<Dataset, DatasetError>) -> (usize, usize) {
+    match result {
+        Err(DatasetError::Csv { line, column, .. }) => (line, column),
+        other => panic!("expected a CSV error, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn a_header_is_detected_from_non_numeric_value_cells() {
+    let dataset = parse("Category,Pi,Pie\nSpeed,1,2\n", HeaderMode::Auto).unwrap();
+    assert_eq!(names(&dataset), (vec!["Pi", "Pie"], vec!["Speed"]));
+    assert_eq!(dataset.categories[0].values, [1.0, 2.0]);
+
+    let dataset = parse("Speed,1,2\nTaste,3,4\n", HeaderMode::Auto).unwrap();
+    assert_eq!(names(&dataset), (vec!["Entity 1", "Entity 2"], vec!["Speed", "Taste"]));
+}
+
+#[test]
+fn header_flags_override_detection() {
+    let dataset = parse("Year,2023,2024\nSpeed,3,4\n", HeaderMode::Present).unwrap();
+    assert_eq!(names(&dataset), (vec!["2023", "2024"], vec!["Speed"]));
+
+    // A text first row read as data fails on its first value cell
+    assert_eq!(position(parse("Category,Pi,Pie\nSpeed,1,2\n", HeaderMode::Absent)), (1, 2));
+
+    let mapping = CsvMapping {
+        values: vec![ColumnRef::Header("pie".to_string()), ColumnRef::Header("PI".to_string())],
+        ..CsvMapping::default()
+    };
+    let dataset = parse_csv_dataset("Category,Pi,Pie\nSpeed,1,2\n", &mapping).unwrap();
+    assert_eq!(names(&dataset).0, ["Pie", "Pi"]);
+    assert_eq!(dataset.categories[0].values, [2.0, 1.0]);
+}
+
+#[test]
+fn quoted_fields_keep_commas_quotes_and_newlines() {
+    let csv = "Category,Pi,Pie\n\"Speed, top\",1,2\n\"Said \"\"hi\"\"\",3,4\n\"Two\nlines\",5,6\nLast,7,8\n";
+    let dataset = parse(csv, HeaderMode::Auto).unwrap();
+    assert_eq!(names(&dataset).1, ["Speed, top", "Said \"hi\"", "Two\nlines", "Last"]);
+    assert_eq!(dataset.categories[3].values, [7.0, 8.0]);
+
+    // Lines inside a quoted field still count towards error positions
+    let csv = "Category,Pi,Pie\n\"Two\nlines\",5,6\nLast,7,x\n";
+    assert_eq!(position(parse(csv, HeaderMode::Auto)), (4, 3));
+    assert_eq!(position(parse("Category,Pi,Pie\n\"Open,1,2\n", HeaderMode::Auto)), (2, 1));
+    assert_eq!(position(parse("Category,Pi,Pie\nSp\"eed,1,2\n", HeaderMode::Auto)), (2, 1));
+}
+
+#[test]
+fn crlf_line_endings_are_accepted() {
+    let dataset = parse("Category,Pi,Pie\r\nSpeed,1,2\r\n\r\nTaste,3,4\r\n", HeaderMode::Auto).unwrap();
+    assert_eq!(names(&dataset), (vec!["Pi", "Pie"], vec!["Speed", "Taste"]));
+    assert_eq!(dataset.categories[1].values, [3.0, 4.0]);
+}
+
+#[test]
+fn ragged_rows_and_non_numeric_values_report_their_position() {
+    assert_eq!(position(parse("Category,Pi,Pie\nSpeed,1,2\nTaste,3\n", HeaderMode::Auto)), (3, 3));
+    assert_eq!(position(parse("Category,Pi,Pie\nSpeed,1,lots\n", HeaderMode::Auto)), (2, 3));
+    let Err(DatasetError::Csv { message, .. }) = parse("Category,Pi,Pie\nSpeed,1,lots\n", HeaderMode::Auto) else {
+        panic!("expected a CSV error");
+    };
+    assert!(message.contains("\"lots\""), "{}", message);
+}
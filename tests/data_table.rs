@@ -0,0 +1,106 @@
+use bevy::prelude::*;
+use bevy::window::ReceivedCharacter;
+use pi_vs_pi::pi_vs_pi::data_table::{handle_cell_typing, CellEdit, DataTableState};
+use pi_vs_pi::pi_vs_pi::dataset::is_valid_value;
+use pi_vs_pi::pi_vs_pi::radius::RadiusConfig;
+use pi_vs_pi::pi_vs_pi::{ChartRotation, CompetitionData, Dataset, EntityDef, GameData, SliceValueChanged};
+
+/// An app editing `category_index`, `entity_index` with an empty buffer
+fn editing(category_index: usize, entity_index: usize) -> App {
+    let mut app = App::new();
+    app.init_resource::<ButtonInput<KeyCode>>()
+        .insert_resource(DataTableState {
+            editing: Some(CellEdit { category_index, entity_index, buffer: String::new() }),
+            ..default()
+        })
+        .insert_resource(GameData::new(Dataset {
+            entities: EntityDef::legacy_pair(),
+            categories: vec![
+                CompetitionData::new("Strength", vec![4.0, 7.0]),
+                CompetitionData::new("Cunning", vec![8.0, 5.0]),
+            ],
+            radius: RadiusConfig::default(),
+        }))
+        .insert_resource(ChartRotation::new(2))
+        .add_event::<ReceivedCharacter>()
+        .add_event::<SliceValueChanged>()
+        .add_systems(Update, handle_cell_typing);
+    app
+}
+
+fn type_text(app: &mut App, text: &str) {
+    app.world.send_event(ReceivedCharacter { window: Entity::PLACEHOLDER, char: text.into() });
+}
+
+fn press(app: &mut App, key: KeyCode) {
+    app.world.resource_mut::<ButtonInput<KeyCode>>().press(key);
+}
+
+fn value(app: &App, category_index: usize, entity_index: usize) -> f32 {
+    app.world.resource::<GameData>().data[category_index].values[entity_index]
+}
+
+fn changed_slices(app: &App) -> usize {
+    app.world.resource::<Events<SliceValueChanged>>().len()
+}
+
+#[test]
+fn only_finite_non_negative_values_are_valid() {
+    assert!(is_valid_value(0.0));
+    assert!(is_valid_value(12.5));
+    assert!(!is_valid_value(-0.5));
+    assert!(!is_valid_value(f32::NAN));
+    assert!(!is_valid_value(f32::INFINITY));
+}
+
+#[test]
+fn enter_commits_the_typed_value() {
+    let mut app = editing(1, 0);
+    type_text(&mut app, "1");
+    type_text(&mut app, "2x.5");
+    app.update();
+    assert_eq!(app.world.resource::<DataTableState>().editing.as_ref().unwrap().buffer, "12.5");
+
+    press(&mut app, KeyCode::Enter);
+    app.update();
+    assert_eq!(value(&app, 1, 0), 12.5);
+    assert_eq!(changed_slices(&app), 1);
+    let state = app.world.resource::<DataTableState>();
+    assert!(state.editing.is_none() && state.status.is_none());
+}
+
+#[test]
+fn enter_keeps_editing_an_invalid_value() {
+    let mut app = editing(0, 1);
+    type_text(&mut app, "-3");
+    press(&mut app, KeyCode::Enter);
+    app.update();
+    assert_eq!(value(&app, 0, 1), 7.0);
+    assert_eq!(changed_slices(&app), 0);
+    let state = app.world.resource::<DataTableState>();
+    assert_eq!(state.editing.as_ref().unwrap().buffer, "-3");
+    assert!(state.status.as_ref().unwrap().contains("\"-3\""));
+}
+
+#[test]
+fn escape_cancels_the_edit() {
+    let mut app = editing(0, 0);
+    app.world.resource_mut::<DataTableState>().status = Some("Invalid value".to_string());
+    type_text(&mut app, "9");
+    press(&mut app, KeyCode::Escape);
+    app.update();
+    assert_eq!(value(&app, 0, 0), 4.0);
+    assert_eq!(changed_slices(&app), 0);
+    let state = app.world.resource::<DataTableState>();
+    assert!(state.editing.is_none() && state.status.is_none());
+}
+
+#[test]
+fn committing_a_cell_dropped_by_a_reload_ends_the_edit() {
+    let mut app = editing(5, 0);
+    type_text(&mut app, "3");
+    press(&mut app, KeyCode::Enter);
+    app.update();
+    assert!(app.world.resource::<DataTableState>().editing.is_none());
+    assert_eq!(changed_slices(&app), 0);
+}
@@ -0,0 +1,117 @@
+use bevy::prelude::Color;
+use pi_vs_pi::pi_vs_pi::dataset::{parse_json_dataset, validate_dataset, DatasetError};
+use pi_vs_pi::pi_vs_pi::Dataset;
+
+fn names(dataset: &Dataset) -> (Vec<&str>, Vec<&str>) {
+    (
+        dataset.entities.iter().map(|entity| entity.name.as_str()).collect(),
+        dataset.categories.iter().map(|category| category.name.as_str()).collect(),
+    )
+}
+
+const ENTITIES: &str = r##""entities": [
+    {"name": "Pi", "color": "#ff0000", "description": "Circular"},
+    {"name": "Pie", "color": "#00ff80"},
+    {"name": "Py", "color": "#0000ff"}
+]"##;
+
+fn with_categories(categories: &str) -> String {
+    format!("{{{}, \"categories\": {}}}", ENTITIES, categories)
+}
+
+#[test]
+fn legacy_arrays_get_the_kobold_and_troglodyte_pair() {
+    let json = r#"[
+        {"name": "Strength", "kobold": 4, "troglodyte": 7},
+        {"name": "Cunning", "kobold": 8.5, "troglodyte": 5}
+    ]"#;
+    let dataset = parse_json_dataset(json).unwrap();
+    assert_eq!(names(&dataset), (vec!["Kobolds", "Troglodytes"], vec!["Strength", "Cunning"]));
+    assert_eq!(dataset.categories[1].values, [8.5, 5.0]);
+}
+
+#[test]
+fn entity_objects_carry_names_colors_and_descriptions() {
+    let dataset = parse_json_dataset(&with_categories(r#"[{"name": "Speed", "values": [1, 2, 3]}]"#)).unwrap();
+    assert_eq!(names(&dataset), (vec!["Pi", "Pie", "Py"], vec!["Speed"]));
+    assert_eq!(dataset.entities[0].color, Color::rgb_u8(255, 0, 0));
+    assert_eq!(dataset.entities[1].color, Color::rgb_u8(0, 255, 128));
+    assert_eq!(dataset.entities[0].description.as_deref(), Some("Circular"));
+    assert_eq!(dataset.entities[1].description, None);
+    assert_eq!(dataset.categories[0].values, [1.0, 2.0, 3.0]);
+
+    // Colors are written back as the same hex strings
+    let json = serde_json::to_string(&dataset).unwrap();
+    assert!(json.contains(r##""color":"#00ff80""##), "{}", json);
+    assert!(!json.contains(r#""description":null"#), "{}", json);
+}
+
+#[test]
+fn categories_take_values_or_a_left_right_pair_but_not_both() {
+    let json = r##"{
+        "entities": [{"name": "A", "color": "#ffffff"}, {"name": "B", "color": "#000000"}],
+        "categories": [{"name": "Pair", "left": 1, "right": 2}, {"name": "List", "values": [3, 4]}]
+    }"##;
+    let dataset = parse_json_dataset(json).unwrap();
+    assert_eq!(dataset.categories[0].values, [1.0, 2.0]);
+    assert_eq!(dataset.categories[1].values, [3.0, 4.0]);
+
+    for category in [
+        r#"{"name": "Both", "values": [1, 2], "left": 1, "right": 2}"#,
+        r#"{"name": "Half", "left": 1}"#,
+        r#"{"name": "Neither"}"#,
+    ] {
+        let result = parse_json_dataset(&format!("[{}]", category));
+        assert!(matches!(result, Err(DatasetError::Json(_))), "{}", category);
+    }
+}
+
+#[test]
+fn each_invalid_dataset_is_reported() {
+    assert!(matches!(parse_json_dataset("[]"), Err(DatasetError::Empty)));
+    assert!(matches!(parse_json_dataset(&with_categories("[]")), Err(DatasetError::Empty)));
+    assert!(matches!(parse_json_dataset("{not json"), Err(DatasetError::Json(_))));
+
+    let one_entity = r##"{
+        "entities": [{"name": "A", "color": "#ffffff"}],
+        "categories": [{"name": "X", "values": [1]}]
+    }"##;
+    assert!(matches!(parse_json_dataset(one_entity), Err(DatasetError::EntityCount(1))));
+
+    let categories = r#"[{"name": "Ok", "values": [1, 2, 3]}, {"name": "Short", "values": [1, 2]}]"#;
+    let result = parse_json_dataset(&with_categories(categories));
+    let Err(DatasetError::ValueCount { index, name, expected, found }) = result else {
+        panic!("expected a value count error, got {:?}", result.map(|_| ()));
+    };
+    assert_eq!((index, name.as_str(), expected, found), (1, "Short", 3, 2));
+
+    let result = parse_json_dataset(&with_categories(r#"[{"name": "Debt", "values": [1, -2, 3]}]"#));
+    let Err(DatasetError::InvalidValue { index, name, entity, value }) = result else {
+        panic!("expected an invalid value error, got {:?}", result.map(|_| ()));
+    };
+    assert_eq!((index, name.as_str(), entity.as_str(), value), (0, "Debt", "Pie", -2.0));
+}
+
+#[test]
+fn validation_rejects_values_json_cannot_express() {
+    let mut dataset = parse_json_dataset(&with_categories(r#"[{"name": "Speed", "values": [1, 2, 3]}]"#)).unwrap();
+    dataset.categories[0].values[2] = f32::NAN;
+    assert!(matches!(validate_dataset(&dataset), Err(DatasetError::InvalidValue { .. })));
+    dataset.categories[0].values[2] = f32::INFINITY;
+    assert!(matches!(validate_dataset(&dataset), Err(DatasetError::InvalidValue { .. })));
+}
+
+#[test]
+fn malformed_hex_colors_are_rejected() {
+    for color in ["red", "#12345", "#gggggg", ""] {
+        let json = format!(
+            r##"{{"entities": [{{"name": "A", "color": "{}"}}, {{"name": "B", "color": "#000000"}}],
+                "categories": [{{"name": "X", "values": [1, 2]}}]}}"##,
+            color
+        );
+        let Err(DatasetError::Json(err)) = parse_json_dataset(&json) else {
+            panic!("expected {:?} to be rejected", color);
+        };
+        assert!(err.to_string().contains("invalid color"), "{}", err);
+    }
+}
@@ -0,0 +1,204 @@
+use pi_vs_pi::tiny_text::font_file::{load_font, parse_bdf, parse_grid, parse_psf, FontError};
+use pi_vs_pi::tiny_text::{CubePos, TinyFont, GLYPH_HEIGHT, GLYPH_WIDTH};
+
+fn cells(font: &TinyFont, c: char) -> Vec<(i32, i32)> {
+    let mut cells: Vec<_> = font.get_glyph(c).unwrap().iter().map(|cube| (cube.x, cube.y)).collect();
+    cells.sort();
+    cells
+}
+
+fn malformed_line(err: FontError) -> usize {
+    match err {
+        FontError::Malformed { line, .. } => line,
+        other => panic!("expected a malformed-line error, got {}", other),
+    }
+}
+
+#[test]
+fn builtin_font_covers_printable_ascii() {
+    let font = TinyFont::new();
+    for c in ' '..='~' {
+        assert!(font.get_glyph(c).is_some(), "{:?}", c);
+    }
+    assert!(cells(&font, ' ').is_empty());
+    // Letters fill the standard cell; descenders drop below the baseline
+    assert!(cells(&font, 'H').iter().all(|&(x, y)| (0..GLYPH_WIDTH).contains(&x) && (0..GLYPH_HEIGHT).contains(&y)));
+    assert_eq!(cells(&font, 'g').iter().map(|&(_, y)| y).min(), Some(-2));
+}
+
+#[test]
+fn builtin_font_covers_latin1_and_common_symbols() {
+    let font = TinyFont::new();
+    for c in ('\u{A0}'..='\u{FF}').chain("→←↑↓✓✗•…€™≤≥≠∞−–—‘’“”".chars()) {
+        assert!(font.get_glyph(c).is_some(), "{:?}", c);
+    }
+    // Accents sit above the base letter, cedillas below the baseline
+    let top = |c| cells(&font, c).iter().map(|&(_, y)| y).max();
+    assert!(top('É') > top('E'));
+    assert!(top('é') > top('e'));
+    assert_eq!(cells(&font, 'ç').iter().map(|&(_, y)| y).min(), Some(-2));
+    let sorted = |glyph: &[CubePos]| {
+        let mut cells: Vec<_> = glyph.iter().map(|cube| (cube.x, cube.y)).collect();
+        cells.sort();
+        cells
+    };
+    assert_eq!(sorted(font.missing_glyph().unwrap()), sorted(&TinyFont::missing_box(GLYPH_WIDTH, GLYPH_HEIGHT)));
+}
+
+#[test]
+fn grid_rows_run_top_to_bottom_from_the_origin() {
+    let font = parse_grid(
+        "// a comment\n\
+         glyph L\n\
+         #..\n\
+         #..\n\
+         ###\n\
+         \n\
+         glyph U+002C 1 -1\n\
+         #\n\
+         #\n",
+    )
+    .unwrap();
+    assert_eq!(cells(&font, 'L'), [(0, 0), (0, 1), (0, 2), (1, 0), (2, 0)]);
+    assert_eq!(cells(&font, ','), [(1, -1), (1, 0)]);
+    assert!(font.get_glyph('A').is_none());
+
+    let kerned = parse_grid("glyph L\n#\n\nkern L U+0054 -1\n").unwrap();
+    assert_eq!(kerned.kerning('L', 'T'), -1);
+    assert_eq!(malformed_line(parse_grid("kern L T\n").unwrap_err()), 1);
+    assert_eq!(TinyFont::new().kerning('T', 'o'), -1);
+}
+
+#[test]
+fn malformed_grid_glyphs_report_their_line() {
+    assert_eq!(malformed_line(parse_grid("glyph A\n##\n#\n").unwrap_err()), 3);
+    assert_eq!(malformed_line(parse_grid("glyph A\n#x\n").unwrap_err()), 2);
+    assert_eq!(malformed_line(parse_grid("\n##\n").unwrap_err()), 2);
+    assert_eq!(malformed_line(parse_grid("glyph AB\n#\n").unwrap_err()), 1);
+    assert_eq!(malformed_line(parse_grid("glyph A 1\n#\n").unwrap_err()), 1);
+    // A repeated glyph is reported where it is defined again
+    assert_eq!(malformed_line(parse_grid("glyph A\n#\n\nglyph A\n#\n").unwrap_err()), 4);
+    assert_eq!(malformed_line(parse_grid("glyph U+D800\n").unwrap_err()), 1);
+    assert_eq!(malformed_line(parse_grid("glyph missing\n#\n\nglyph missing\n#\n").unwrap_err()), 4);
+}
+
+#[test]
+fn grid_and_bdf_fonts_can_replace_the_missing_glyph() {
+    let font = parse_grid("glyph missing 0 1\n#\n").unwrap();
+    assert_eq!(font.len(), 0);
+    let missing: Vec<_> = font.missing_glyph().unwrap().iter().map(|cube| (cube.x, cube.y)).collect();
+    assert_eq!(missing, [(0, 1)]);
+    // Fonts that do not define one draw the standard box
+    assert_eq!(parse_grid("glyph A\n#\n").unwrap().missing_glyph().map(Vec::len), Some(20));
+
+    let bdf = parse_bdf(&BDF.replace("CHARS 2", "DEFAULT_CHAR 84\nCHARS 2")).unwrap();
+    assert_eq!(bdf.missing_glyph().map(Vec::len), Some(5));
+}
+
+const BDF: &str = "STARTFONT 2.1
+FONT -tiny-test
+SIZE 8 75 75
+FONTBOUNDINGBOX 4 4 0 -1
+CHARS 2
+STARTCHAR T
+ENCODING 84
+DWIDTH 4 0
+BBX 3 3 0 0
+BITMAP
+E0
+40
+40
+ENDCHAR
+STARTCHAR unencoded
+ENCODING -1
+BBX 1 1 0 0
+BITMAP
+80
+ENDCHAR
+STARTCHAR j
+ENCODING 106
+BBX 2 4 1 -1
+BITMAP
+40
+40
+40
+80
+ENDCHAR
+ENDFONT
+";
+
+#[test]
+fn bdf_glyphs_keep_their_baseline() {
+    let font = parse_bdf(BDF).unwrap();
+    assert_eq!(cells(&font, 'T'), [(0, 2), (1, 0), (1, 1), (1, 2), (2, 2)]);
+    assert_eq!(cells(&font, 'j'), [(1, -1), (2, 0), (2, 1), (2, 2)]);
+}
+
+#[test]
+fn malformed_bdf_glyphs_are_reported() {
+    assert_eq!(malformed_line(parse_bdf("FONT x\n").unwrap_err()), 1);
+    let no_box = BDF.replace("BBX 3 3 0 0\n", "");
+    assert_eq!(malformed_line(parse_bdf(&no_box).unwrap_err()), 9);
+    let short = BDF.replace("E0\n40\n40\n", "E0\n40\n");
+    assert_eq!(malformed_line(parse_bdf(&short).unwrap_err()), 13);
+    let bad_hex = BDF.replace("E0\n", "G0\n");
+    assert_eq!(malformed_line(parse_bdf(&bad_hex).unwrap_err()), 11);
+    let bad_box = BDF.replace("BBX 3 3 0 0", "BBX 3 three 0 0");
+    assert_eq!(malformed_line(parse_bdf(&bad_box).unwrap_err()), 9);
+}
+
+/// A 2-glyph, 8x3 PSF2 font; the second glyph is a horizontal bar
+fn psf2(table: Option<&[u8]>) -> Vec<u8> {
+    let mut data = vec![0x72, 0xb5, 0x4a, 0x86];
+    for value in [0u32, 32, table.is_some() as u32, 2, 3, 3, 8] {
+        data.extend(value.to_le_bytes());
+    }
+    data.extend([0x00, 0x00, 0x00, 0x00, 0xF0, 0x00]);
+    data.extend(table.unwrap_or(&[]));
+    data
+}
+
+#[test]
+fn psf_glyphs_map_through_the_unicode_table() {
+    let font = parse_psf(&psf2(Some(b" \xFF-\xE2\x80\x94\xFE-\xCC\x81\xFF"))).unwrap();
+    assert!(cells(&font, ' ').is_empty());
+    assert_eq!(cells(&font, '-'), [(0, 1), (1, 1), (2, 1), (3, 1)]);
+    // Without a table glyph N is code point N
+    let mut psf1 = vec![0x36, 0x04, 0x00, 2];
+    psf1.extend(std::iter::repeat_n(0, 256 * 2));
+    psf1[4 + 65 * 2] = 0x81;
+    assert_eq!(cells(&parse_psf(&psf1).unwrap(), 'A'), [(0, 1), (7, 1)]);
+}
+
+#[test]
+fn truncated_psf_fonts_are_rejected() {
+    let data = psf2(None);
+    assert!(matches!(parse_psf(&data[..data.len() - 1]), Err(FontError::InvalidPsf(_))));
+    assert!(matches!(parse_psf(&data[..10]), Err(FontError::InvalidPsf(_))));
+    assert!(matches!(parse_psf(&psf2(Some(b" \xFF"))), Err(FontError::InvalidPsf(_))));
+    assert!(matches!(parse_psf(b"not a font"), Err(FontError::InvalidPsf(_))));
+    // Zero-sized glyphs and a header shorter than PSF2's own
+    assert!(matches!(parse_psf(&[0x36, 0x04, 0x00, 0x00]), Err(FontError::InvalidPsf(_))));
+    let mut empty = psf2(None);
+    empty[20..24].fill(0);
+    empty[28..32].fill(0);
+    assert!(matches!(parse_psf(&empty), Err(FontError::InvalidPsf(_))));
+    let mut short_header = psf2(None);
+    short_header[8..12].copy_from_slice(&16u32.to_le_bytes());
+    assert!(matches!(parse_psf(&short_header), Err(FontError::InvalidPsf(_))));
+}
+
+#[test]
+fn load_font_picks_the_format_from_the_extension() {
+    let dir = std::env::temp_dir().join(format!("tiny_font_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let bdf = dir.join("test.bdf");
+    std::fs::write(&bdf, BDF).unwrap();
+    let grid = dir.join("test.txt");
+    std::fs::write(&grid, "glyph T\n###\n").unwrap();
+
+    assert_eq!(cells(&load_font(&bdf).unwrap(), 'T').len(), 5);
+    assert_eq!(cells(&TinyFont::load(&grid).unwrap(), 'T').len(), 3);
+    assert!(matches!(load_font(&dir.join("missing.psf")), Err(FontError::Io(_))));
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use pi_vs_pi::pi_vs_pi::chart::ChartSpec;
+use pi_vs_pi::pi_vs_pi::hot_reload::apply_dataset;
+use pi_vs_pi::pi_vs_pi::radius::RadiusConfig;
+use pi_vs_pi::pi_vs_pi::scoreboard::{setup_stadium, update_scoreboards, ScoreTween};
+use pi_vs_pi::pi_vs_pi::{
+    rebuild_charts, update_charts, ChartRotation, CompetitionData, Dataset, EntityDef, GameData, PieSlice, RebuildCharts,
+};
+use pi_vs_pi::tiny_text::TinyTextPlugin;
+
+fn dataset(entities: usize) -> Dataset {
+    Dataset {
+        entities: (0..entities).map(|i| EntityDef::new(format!("Side {}", i + 1), EntityDef::default_color(i))).collect(),
+        categories: vec![
+            CompetitionData::new("Strength", (1..=entities).map(|v| v as f32).collect()),
+            CompetitionData::new("Cunning", vec![2.0; entities]),
+        ],
+        radius: RadiusConfig::default(),
+    }
+}
+
+fn slice_count(app: &mut App) -> usize {
+    app.world.query::<&PieSlice>().iter(&app.world).count()
+}
+
+#[test]
+fn reloading_fewer_entities_rebuilds_without_touching_stale_slices() {
+    let mut app = App::new();
+    app.init_resource::<Assets<Mesh>>()
+        .init_resource::<Assets<StandardMaterial>>()
+        .init_resource::<Time>()
+        .init_resource::<ScoreTween>()
+        .insert_resource(GameData::new(dataset(3)))
+        .insert_resource(ChartRotation::new(3))
+        .insert_resource(ChartSpec::default())
+        .add_event::<RebuildCharts>()
+        .add_plugins(TinyTextPlugin)
+        .add_systems(Startup, setup_stadium)
+        .add_systems(Update, (update_charts, update_scoreboards, rebuild_charts));
+    app.world.send_event(RebuildCharts);
+    app.update();
+    assert_eq!(slice_count(&mut app), 3 * 2);
+
+    // As `watch_dataset_file` does: swap the data in, then ask for a rebuild
+    // whose despawns only land after this frame's systems have run
+    app.world.resource_scope(|world, mut game_data: Mut<GameData>| {
+        apply_dataset(&mut game_data, &mut world.resource_mut::<ChartRotation>(), dataset(2));
+    });
+    app.world.send_event(RebuildCharts);
+    app.update();
+    app.update();
+    assert_eq!(slice_count(&mut app), 2 * 2);
+    assert_eq!(app.world.resource::<ChartRotation>().angles.len(), 2);
+}
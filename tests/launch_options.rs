@@ -0,0 +1,32 @@
+use pi_vs_pi::pi_vs_pi::csv_import::{ColumnRef, HeaderMode};
+use pi_vs_pi::pi_vs_pi::parse_args;
+
+fn args(line: &str) -> Vec<String> {
+    line.split_whitespace().map(String::from).collect()
+}
+
+#[test]
+fn columns_and_header_mode_are_read() {
+    let options = parse_args(args("data.csv --name Team --left 3 --right Away --no-header")).unwrap();
+    assert_eq!(options.dataset.unwrap().to_str(), Some("data.csv"));
+    assert_eq!(options.csv_mapping.name, ColumnRef::Header("Team".to_string()));
+    assert_eq!(options.csv_mapping.values, [ColumnRef::Index(2), ColumnRef::Header("Away".to_string())]);
+    assert_eq!(options.csv_mapping.header, HeaderMode::Absent);
+}
+
+#[test]
+fn right_after_a_one_column_values_list_adds_the_column() {
+    let options = parse_args(args("--values a --right b")).unwrap();
+    assert_eq!(options.csv_mapping.values, [ColumnRef::Header("a".to_string()), ColumnRef::Header("b".to_string())]);
+    let options = parse_args(args("--values a,b,c --left d")).unwrap();
+    assert_eq!(options.csv_mapping.values[0], ColumnRef::Header("d".to_string()));
+    assert_eq!(options.csv_mapping.values.len(), 3);
+}
+
+#[test]
+fn bad_arguments_are_usage_errors() {
+    assert_eq!(parse_args(args("--right")).unwrap_err(), "--right expects a column");
+    assert_eq!(parse_args(args("--bogus")).unwrap_err(), "unknown option --bogus");
+    assert_eq!(parse_args(args("a.csv b.csv")).unwrap_err(), "unexpected argument b.csv");
+    assert_eq!(parse_args(args("--help")).unwrap_err(), "");
+}
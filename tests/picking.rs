@@ -0,0 +1,37 @@
+use std::f32::consts::PI;
+
+use bevy::math::Ray3d;
+use bevy::prelude::*;
+use pi_vs_pi::pi_vs_pi::picking::{chart_local_hit, wedge_contains};
+
+fn ray_toward(target: Vec3) -> Ray3d {
+    let origin = Vec3::new(0.0, 5.0, 25.0);
+    Ray3d::new(origin, target - origin)
+}
+
+#[test]
+fn hit_is_reported_in_the_unrotated_chart_frame() {
+    let chart = Transform::from_xyz(-6.0, 7.0, 0.0).with_rotation(Quat::from_rotation_z(PI / 2.0));
+    // A point straight above the center on screen is at angle 0 before the quarter turn
+    let (point, distance) = chart_local_hit(ray_toward(Vec3::new(-6.0, 10.0, 0.5)), &chart, 0.5).unwrap();
+    assert!((point - Vec2::new(3.0, 0.0)).length() < 1e-4, "{:?}", point);
+    assert!(distance > 0.0);
+}
+
+#[test]
+fn ray_parallel_to_the_chart_misses() {
+    let chart = Transform::from_xyz(0.0, 0.0, 0.0);
+    let ray = Ray3d::new(Vec3::new(0.0, 0.0, 5.0), Vec3::X);
+    assert!(chart_local_hit(ray, &chart, 0.5).is_none());
+}
+
+#[test]
+fn wedge_contains_checks_angle_and_radius() {
+    let (start, end, radius) = (PI / 2.0, PI, 4.0);
+    assert!(wedge_contains(start, end, radius, Vec2::new(-1.0, 1.0)));
+    assert!(!wedge_contains(start, end, radius, Vec2::new(-3.0, 3.0)));
+    assert!(!wedge_contains(start, end, radius, Vec2::new(1.0, 1.0)));
+    // Angles below the x axis wrap to the far end of the circle
+    assert!(wedge_contains(1.5 * PI, 2.0 * PI, radius, Vec2::new(1.0, -1.0)));
+    assert!(!wedge_contains(1.0, 1.0, radius, Vec2::new(0.5, 0.8)));
+}
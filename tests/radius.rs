@@ -0,0 +1,94 @@
+use pi_vs_pi::pi_vs_pi::dataset::{parse_json_dataset, DatasetError};
+use pi_vs_pi::pi_vs_pi::radius::{RadiusConfig, RadiusStrategy};
+
+fn radius(strategy: RadiusStrategy, share: f32) -> f32 {
+    RadiusConfig::new(strategy, 0.0).radius(share, 4, 10.0)
+}
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!((actual - expected).abs() < 1e-5, "{} != {}", actual, expected);
+}
+
+#[test]
+fn area_preserving_radii_are_the_square_root_of_linear_ones() {
+    for share in [0.0, 0.09, 0.25, 0.5, 1.0] {
+        assert_close(radius(RadiusStrategy::Linear, share), 10.0 * share);
+        assert_close(radius(RadiusStrategy::AreaPreserving, share), 10.0 * share.sqrt());
+    }
+    // Quartering a slice's area halves its radius
+    assert_close(radius(RadiusStrategy::AreaPreserving, 0.25), radius(RadiusStrategy::AreaPreserving, 1.0) / 2.0);
+}
+
+#[test]
+fn logarithmic_radii_run_from_zero_to_the_base_radius() {
+    assert_close(radius(RadiusStrategy::Logarithmic, 0.0), 0.0);
+    assert_close(radius(RadiusStrategy::Logarithmic, 1.0), 10.0);
+    // Small shares are lifted above their linear radius
+    assert!(radius(RadiusStrategy::Logarithmic, 0.1) > radius(RadiusStrategy::Linear, 0.1));
+}
+
+#[test]
+fn fixed_radii_ignore_the_share() {
+    for share in [0.0, 0.3, 1.0] {
+        assert_close(radius(RadiusStrategy::Fixed, share), 10.0 / 4.0);
+    }
+    assert_close(RadiusConfig::new(RadiusStrategy::Fixed, 0.0).radius(0.3, 2, 10.0), 5.0);
+}
+
+#[test]
+fn shares_outside_zero_to_one_are_clamped() {
+    assert_close(radius(RadiusStrategy::Linear, -0.5), 0.0);
+    assert_close(radius(RadiusStrategy::AreaPreserving, 2.0), 10.0);
+}
+
+#[test]
+fn the_floor_lifts_only_smaller_radii() {
+    let floored = RadiusConfig::new(RadiusStrategy::Linear, 0.2);
+    assert_close(floored.radius(0.0, 2, 10.0), 2.0);
+    assert_close(floored.radius(0.1, 2, 10.0), 2.0);
+    assert_close(floored.radius(0.6, 2, 10.0), 6.0);
+}
+
+#[test]
+fn out_of_range_floors_are_clamped_when_built() {
+    assert_eq!(RadiusConfig::new(RadiusStrategy::Linear, -0.5).min_fraction, 0.0);
+    assert_eq!(RadiusConfig::new(RadiusStrategy::Linear, 3.0).min_fraction, 1.0);
+    assert_eq!(RadiusConfig::new(RadiusStrategy::Linear, f32::NAN).min_fraction, 0.0);
+    assert_close(RadiusConfig::new(RadiusStrategy::Linear, 3.0).radius(0.5, 2, 10.0), 10.0);
+}
+
+#[test]
+fn dataset_files_with_out_of_range_floors_are_rejected() {
+    let dataset = |min_fraction: &str| {
+        format!(
+            r##"{{"entities": [{{"name": "A", "color": "#ffffff"}}, {{"name": "B", "color": "#000000"}}],
+                "categories": [{{"name": "X", "values": [1, 2]}}],
+                "radius": {{"strategy": "logarithmic", "min_fraction": {}}}}}"##,
+            min_fraction
+        )
+    };
+    let loaded = parse_json_dataset(&dataset("0.25")).unwrap();
+    assert_eq!(loaded.radius, RadiusConfig::new(RadiusStrategy::Logarithmic, 0.25));
+    for bad in ["-0.1", "1.5"] {
+        let Err(DatasetError::Json(err)) = parse_json_dataset(&dataset(bad)) else {
+            panic!("expected min_fraction {} to be rejected", bad);
+        };
+        assert!(err.to_string().contains("min_fraction must be between 0 and 1"), "{}", err);
+    }
+}
+
+#[test]
+fn next_cycles_through_every_strategy_and_back() {
+    let mut strategy = RadiusStrategy::default();
+    let mut seen = vec![strategy];
+    for _ in 0..3 {
+        strategy = strategy.next();
+        assert!(!seen.contains(&strategy));
+        seen.push(strategy);
+    }
+    assert_eq!(
+        seen,
+        [RadiusStrategy::Linear, RadiusStrategy::AreaPreserving, RadiusStrategy::Logarithmic, RadiusStrategy::Fixed]
+    );
+    assert_eq!(strategy.next(), RadiusStrategy::Linear);
+}
@@ -0,0 +1,288 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use pi_vs_pi::pi_vs_pi::radius::RadiusConfig;
+use pi_vs_pi::pi_vs_pi::scoreboard::{
+    dataset_precision, rebuild_stadium, setup_stadium, summarize_category, update_scoreboards, Easing, LineRole, ScoreBoard, ScoreLine,
+    ScoreTween,
+};
+use pi_vs_pi::pi_vs_pi::chart::{spawn_chart, ChartSpec};
+use pi_vs_pi::pi_vs_pi::{update_charts, ChartRotation, CompetitionData, Dataset, EntityDef, GameData, RebuildCharts};
+use pi_vs_pi::tiny_text::{TinyFont, TinyText3d, TinyTextPlugin};
+
+/// Entities added, reparented or modified during the last frame, and how many
+/// of them were scoreboard lines
+#[derive(Resource, Default)]
+struct Touched {
+    entities: usize,
+    lines: usize,
+}
+
+type TouchedFilter = Or<(
+    Added<Parent>,
+    Changed<Children>,
+    Changed<ScoreLine>,
+    Changed<Transform>,
+    Changed<Handle<StandardMaterial>>,
+)>;
+
+fn record_touched(
+    mut touched: ResMut<Touched>,
+    changed: Query<(), TouchedFilter>,
+    lines: Query<(), Changed<ScoreLine>>,
+) {
+    touched.entities = changed.iter().count();
+    touched.lines = lines.iter().count();
+}
+
+fn app() -> App {
+    app_with_tween(ScoreTween::default())
+}
+
+fn spawn_charts(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    game_data: Res<GameData>,
+) {
+    for entity_index in 0..game_data.entity_count() {
+        let spec = ChartSpec::default().at(Vec3::X * 20.0 * entity_index as f32);
+        spawn_chart(&mut commands, &mut meshes, &mut materials, &game_data, &spec, entity_index);
+    }
+}
+
+fn app_with_tween(tween: ScoreTween) -> App {
+    let mut app = App::new();
+    app.init_resource::<Assets<Mesh>>()
+        .init_resource::<Assets<StandardMaterial>>()
+        .init_resource::<Touched>()
+        .init_resource::<Time>()
+        .insert_resource(tween)
+        .insert_resource(GameData::new(Dataset {
+            entities: EntityDef::legacy_pair(),
+            categories: vec![
+                CompetitionData::new("Strength", vec![4.0, 7.0]),
+                CompetitionData::new("Cunning", vec![8.0, 5.0]),
+            ],
+            radius: RadiusConfig::default(),
+        }))
+        .insert_resource(ChartRotation::new(2))
+        .add_plugins(TinyTextPlugin)
+        .add_systems(Startup, (setup_stadium, spawn_charts))
+        .add_event::<RebuildCharts>()
+        .add_systems(Update, (update_charts, update_scoreboards, rebuild_stadium))
+        .add_systems(Last, record_touched);
+    app
+}
+
+fn line_texts(app: &mut App) -> Vec<(LineRole, String)> {
+    let mut lines: Vec<_> = app
+        .world
+        .query::<&ScoreLine>()
+        .iter(&app.world)
+        .map(|line| (line.role, line.text.clone()))
+        .collect();
+    lines.sort_by(|a, b| a.1.cmp(&b.1));
+    lines
+}
+
+fn board_entities(app: &mut App) -> Vec<Entity> {
+    app.world.query_filtered::<Entity, With<ScoreBoard>>().iter(&app.world).collect()
+}
+
+#[test]
+fn idle_frame_touches_no_scoreboard_entities() {
+    let mut app = app();
+    app.update();
+    assert!(app.world.resource::<Touched>().entities > 0, "first frame builds the boards");
+
+    let entity_count = app.world.entities().len();
+    let rotation = app.world.resource::<ChartRotation>().clone();
+    app.update();
+    assert_eq!(app.world.resource::<Touched>().entities, 0);
+    assert_eq!(app.world.entities().len(), entity_count);
+    // The charts are at rest, so the rotation systems leave everything alone
+    assert_eq!(*app.world.resource::<ChartRotation>(), rotation);
+}
+
+#[test]
+fn selection_change_rebuilds_only_changed_lines() {
+    let mut app = app();
+    app.update();
+    let boards = board_entities(&mut app);
+    assert!(line_texts(&mut app).contains(&(LineRole::Title, "STRENGTH".to_string())));
+
+    app.world.resource_mut::<GameData>().selected_category = 1;
+    app.update();
+    let lines = line_texts(&mut app);
+    assert!(lines.contains(&(LineRole::Title, "CUNNING".to_string())));
+    assert!(lines.contains(&(LineRole::Score, "8".to_string())));
+    assert!(lines.contains(&(LineRole::Detail, "KOBOLDS 62%".to_string())));
+    // Panels are kept; the margin text is +3 in both categories but changes
+    // color with the winner, so every line except the two names is rebuilt
+    assert_eq!(board_entities(&mut app), boards);
+    assert_eq!(app.world.resource::<Touched>().lines, 5);
+}
+
+#[test]
+fn rebuilds_respawn_the_stadium_only_when_the_entity_count_changes() {
+    let mut app = app();
+    app.update();
+    let boards = board_entities(&mut app);
+    assert_eq!(boards.len(), 3);
+
+    // Toggles and same-size reloads keep the panels and rewrite their lines
+    app.world.resource_mut::<GameData>().data[0].values = vec![9.0, 1.0];
+    app.world.send_event(RebuildCharts);
+    app.update();
+    assert_eq!(board_entities(&mut app), boards);
+    assert!(line_texts(&mut app).contains(&(LineRole::Score, "9".to_string())));
+
+    let mut game_data = app.world.resource_mut::<GameData>();
+    game_data.entities.push(EntityDef::new("Goblins", EntityDef::default_color(2)));
+    for entry in &mut game_data.data {
+        entry.values.push(1.0);
+    }
+    app.world.send_event(RebuildCharts);
+    app.update();
+    app.update();
+    let rebuilt = board_entities(&mut app);
+    assert_eq!(rebuilt.len(), 4);
+    assert!(rebuilt.iter().all(|board| !boards.contains(board)));
+}
+
+#[test]
+fn long_category_names_wrap_to_fit_the_panel() {
+    let mut app = app();
+    let name = "Average Annual Rainfall In Millimeters";
+    app.world.resource_mut::<GameData>().data[0] = CompetitionData::new(name, vec![4.0, 7.0]);
+    app.update();
+
+    let font = TinyFont::new();
+    let mut titles = app.world.query::<(&ScoreLine, &TinyText3d)>();
+    let (_, text) = titles.iter(&app.world).find(|(line, _)| line.text == name.to_uppercase()).unwrap();
+    let layout = text.layout(&font);
+    assert!(layout.lines.len() > 1);
+    assert_eq!(layout.lines.iter().map(|line| line.text.as_str()).collect::<Vec<_>>().join(" "), name.to_uppercase());
+    let fit = text.fit.unwrap();
+    assert!(layout.bounds.size().x <= fit.x + 1e-3 && layout.bounds.size().y <= fit.y + 1e-3);
+}
+
+#[test]
+fn score_lines_are_leds_over_off_pixels() {
+    let mut app = app();
+    app.update();
+    let mut lines = app.world.query::<(&ScoreLine, &Children)>();
+    for (line, children) in lines.iter(&app.world) {
+        // Lit digits and their backplate are separate meshes
+        let expected = if line.role == LineRole::Score { 2 } else { 1 };
+        assert_eq!(children.len(), expected, "{:?} {}", line.role, line.text);
+    }
+    let materials = app.world.resource::<Assets<StandardMaterial>>();
+    assert!(materials.iter().any(|(_, material)| material.unlit));
+}
+
+fn trio() -> Vec<EntityDef> {
+    let mut entities = EntityDef::legacy_pair();
+    entities.push(EntityDef::new("Gnolls", EntityDef::default_color(2)));
+    entities
+}
+
+#[test]
+fn summary_names_the_winner_with_signed_margin_and_share() {
+    let entities = trio();
+    let summary = summarize_category(&entities, &CompetitionData::new("Speed", vec![2.0, 5.5, 2.5]), 1);
+    assert_eq!(summary.winner, Some(1));
+    assert_eq!(summary.margin, 3.0);
+    assert_eq!(summary.score, "+3.0");
+    assert_eq!(summary.detail, "TROGLODYTES 55%");
+    assert_eq!(summary.color, entities[1].color);
+}
+
+#[test]
+fn summary_reports_ties_for_first() {
+    let entities = trio();
+    let summary = summarize_category(&entities, &CompetitionData::new("Guile", vec![6.0, 2.0, 6.0]), 0);
+    assert_eq!(summary.winner, None);
+    assert_eq!(summary.margin, 0.0);
+    assert_eq!(summary.score, "TIE");
+    assert_eq!(summary.detail, "KOBOLDS = GNOLLS 43%");
+
+    // An all-zero category is a tie between everyone with an even share
+    let summary = summarize_category(&entities, &CompetitionData::new("Luck", vec![0.0; 3]), 0);
+    assert_eq!(summary.winner, None);
+    assert!((summary.share - 100.0 / 3.0).abs() < 1e-4);
+    assert_eq!(summary.detail, "KOBOLDS = TROGLODYTES = GNOLLS 33%");
+}
+
+#[test]
+fn precision_follows_the_most_precise_value() {
+    let whole = [CompetitionData::new("A", vec![4.0, 7.0])];
+    assert_eq!(dataset_precision(&whole), 0);
+    let mixed = [CompetitionData::new("A", vec![4.0, 7.25]), CompetitionData::new("B", vec![0.5, 1.0])];
+    assert_eq!(dataset_precision(&mixed), 2);
+    let long = [CompetitionData::new("A", vec![1.0 / 3.0])];
+    assert_eq!(dataset_precision(&long), 3);
+    assert_eq!(dataset_precision(&[]), 0);
+}
+
+#[test]
+fn easing_curves_run_from_zero_to_one() {
+    for easing in [Easing::Linear, Easing::EaseInCubic, Easing::EaseOutCubic, Easing::EaseInOutCubic] {
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert_eq!(easing.apply(1.0), 1.0);
+        assert_eq!(easing.apply(2.0), 1.0);
+    }
+    assert_eq!(Easing::Linear.apply(0.25), 0.25);
+    assert!(Easing::EaseOutCubic.apply(0.25) > 0.25);
+    assert!(Easing::EaseInCubic.apply(0.25) < 0.25);
+    assert_eq!(Easing::EaseInOutCubic.apply(0.5), 0.5);
+}
+
+fn score_texts(app: &mut App) -> Vec<String> {
+    let mut scores: Vec<_> = line_texts(app)
+        .into_iter()
+        .filter(|(role, _)| *role == LineRole::Score)
+        .map(|(_, text)| text)
+        .collect();
+    scores.sort();
+    scores
+}
+
+#[test]
+fn scores_count_up_and_rebuild_only_when_the_rounded_value_changes() {
+    let mut app = app_with_tween(ScoreTween { enabled: true, duration: 1.0, easing: Easing::Linear });
+    app.update();
+    // The first values are shown at once
+    assert_eq!(score_texts(&mut app), ["+3", "4", "7"]);
+
+    app.world.resource_mut::<GameData>().selected_category = 1;
+    app.update();
+    assert_eq!(score_texts(&mut app), ["+3", "4", "7"], "counting starts from the old values");
+
+    // Kobolds count 4 -> 8 and Troglodytes 7 -> 5; at a tenth of a second both
+    // still round to their old values, so no digits are rebuilt
+    let frame = Duration::from_millis(100);
+    app.world.resource_mut::<Time>().advance_by(frame);
+    app.update();
+    assert_eq!(score_texts(&mut app), ["+3", "4", "7"]);
+    assert_eq!(app.world.resource::<Touched>().lines, 0);
+
+    for _ in 0..3 {
+        app.world.resource_mut::<Time>().advance_by(frame);
+        app.update();
+    }
+    // 4.0 + 4 * 0.4 and 7.0 - 2 * 0.4
+    assert_eq!(score_texts(&mut app), ["+3", "6", "6"]);
+
+    for _ in 0..10 {
+        app.world.resource_mut::<Time>().advance_by(frame);
+        app.update();
+    }
+    assert_eq!(score_texts(&mut app), ["+3", "5", "8"]);
+
+    // Once settled the boards are idle again
+    app.world.resource_mut::<Time>().advance_by(frame);
+    app.update();
+    assert_eq!(app.world.resource::<Touched>().entities, 0);
+}
@@ -0,0 +1,549 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy::render::mesh::VertexAttributeValues;
+use pi_vs_pi::tiny_text::color::{gradient, off_pixel_voxels, span_colors, Painter, GRADIENT_STEPS};
+use pi_vs_pi::tiny_text::voxel::build_voxel_mesh;
+use pi_vs_pi::tiny_text::{
+    GlyphMetrics, TextAdvance, TextAlign, TextFill, TextMaterial, TextMeshMode, TextShadow, TextStyle, TinyFont, TinyText, TinyText3d, TinyText3dBundle, TinyTextAssets, TinyTextPlugin,
+    TinyTextSpawner, VerticalAlign, VoxelShape,
+};
+
+fn app() -> App {
+    let mut app = App::new();
+    app.init_resource::<Assets<Mesh>>()
+        .init_resource::<Assets<StandardMaterial>>()
+        .add_plugins(TinyTextPlugin);
+    app
+}
+
+fn cube_count(text: &str) -> usize {
+    let font = TinyFont::new();
+    text.chars().filter_map(|c| font.get_glyph(c)).map(Vec::len).sum()
+}
+
+fn children_of(app: &App, entity: Entity) -> usize {
+    app.world.get::<Children>(entity).map_or(0, |children| children.len())
+}
+
+#[test]
+fn text_entity_gets_one_child_cube_per_glyph_cube() {
+    let mut app = app();
+    let text = app
+        .world
+        .spawn(TinyText3dBundle::new(TinyText3d::new("Hi!", Color::WHITE), Transform::default()))
+        .id();
+    app.update();
+    assert_eq!(children_of(&app, text), cube_count("Hi!"));
+}
+
+#[test]
+fn changing_the_component_replaces_the_cubes() {
+    let mut app = app();
+    let text = app
+        .world
+        .spawn(TinyText3dBundle::new(TinyText3d::new("1", Color::WHITE), Transform::default()))
+        .id();
+    app.update();
+    let before = app.world.entities().len();
+
+    app.world.get_mut::<TinyText3d>(text).unwrap().text = "88".to_string();
+    app.update();
+    assert_eq!(children_of(&app, text), cube_count("88"));
+    assert_eq!(app.world.entities().len(), before - cube_count("1") as u32 + cube_count("88") as u32);
+
+    // Unchanged text is left alone
+    let children = app.world.get::<Children>(text).unwrap().to_vec();
+    app.update();
+    assert_eq!(app.world.get::<Children>(text).unwrap().to_vec(), children);
+}
+
+#[test]
+fn despawning_the_parent_removes_the_whole_text() {
+    let mut app = app();
+    let text = app
+        .world
+        .spawn(TinyText3dBundle::new(TinyText3d::new("Gone", Color::WHITE), Transform::default()))
+        .id();
+    app.update();
+    assert!(app.world.entities().len() > 1);
+
+    app.world.entity_mut(text).despawn_recursive();
+    app.update();
+    assert_eq!(app.world.entities().len(), 0);
+}
+
+#[test]
+fn alignment_places_the_text_around_the_entity() {
+    let font = TinyFont::new();
+    let text = TinyText3d::new("AB", Color::WHITE).with_scale(Vec3::ONE).with_spacing(Vec3::X * 6.0);
+    // Two characters span 6 + 5 = 11 cubes; cube centers sit half a cube inside
+    assert_eq!(text.origin(&font), Vec3::ZERO);
+    let centered = text.clone().with_align(TextAlign::Center, VerticalAlign::Middle);
+    assert_eq!(centered.origin(&font), Vec3::new(-5.0, -3.0, 0.0));
+    let right = text.with_align(TextAlign::Right, VerticalAlign::Top);
+    assert_eq!(right.origin(&font), Vec3::new(-10.5, -6.5, 0.0));
+}
+
+#[test]
+fn unknown_characters_draw_the_missing_glyph() {
+    let font = TinyFont::new();
+    let missing = font.missing_glyph().unwrap().len();
+    assert!(font.get_glyph('\u{4E2D}').is_none());
+    // Control characters draw nothing rather than a box
+    let boxed = TinyText::build_mesh(&font, "\u{4E2D}", Vec3::ZERO, Vec3::ONE, Vec3::X * 6.0, TextAdvance::Fixed);
+    let with_tab = TinyText::build_mesh(&font, "\t\u{4E2D}", Vec3::ZERO, Vec3::ONE, Vec3::ZERO, TextAdvance::Fixed);
+    assert!(!boxed.indices().unwrap().is_empty());
+    assert_eq!(positions(&with_tab).len(), positions(&boxed).len());
+
+    let mut app = app();
+    let text = app
+        .world
+        .spawn(TinyText3dBundle::new(TinyText3d::new("é\u{4E2D}", Color::WHITE), Transform::default()))
+        .id();
+    app.update();
+    assert_eq!(children_of(&app, text), cube_count("é") + missing);
+
+    // Without a missing glyph the character is left out
+    app.world.resource_mut::<TinyFont>().set_missing_glyph(None);
+    app.world.get_mut::<TinyText3d>(text).unwrap().text = "é\u{4E2D}".to_string();
+    app.update();
+    assert_eq!(children_of(&app, text), cube_count("é"));
+}
+
+#[test]
+fn proportional_advance_follows_the_glyph_bounds() {
+    let mut font = TinyFont::new();
+    assert_eq!(font.metrics('i'), GlyphMetrics::measure(font.get_glyph('i').unwrap()));
+    assert!(font.metrics('i').width < font.metrics('W').width);
+    assert_eq!(font.metrics('%').width, 8);
+    assert_eq!(font.metrics(' ').width, 0);
+
+    // Each glyph's leftmost cube starts one empty column after the previous glyph
+    let pens = TinyText::pen_positions(&font, "i%W", Vec3::ZERO, Vec3::ONE, Vec3::ZERO, TextAdvance::Proportional);
+    let mut left = 0.0;
+    for (c, pen) in &pens {
+        let metrics = font.metrics(*c);
+        assert_eq!(pen.x + metrics.min_x as f32, left);
+        left += (metrics.width + 1) as f32;
+    }
+    let width = TinyText::measure_width(&font, "i%W", Vec3::ONE, Vec3::ZERO, TextAdvance::Proportional);
+    assert_eq!(width, left - 1.0);
+
+    // Kerning moves every following glyph
+    let before = TinyText::measure_width(&font, "AV", Vec3::ONE, Vec3::ZERO, TextAdvance::Proportional);
+    font.set_kerning('A', 'V', -2);
+    assert_eq!(TinyText::measure_width(&font, "AV", Vec3::ONE, Vec3::ZERO, TextAdvance::Proportional), before - 2.0);
+    let pens = TinyText::pen_positions(&font, "AVA", Vec3::ZERO, Vec3::ONE, Vec3::ZERO, TextAdvance::Proportional);
+    assert_eq!(pens[2].1.x, 2.0 * 6.0 - 2.0);
+    assert_eq!(font.kerning('V', 'A'), 0);
+}
+
+#[test]
+fn proportional_text_is_narrower_and_still_aligned() {
+    let font = TinyFont::new();
+    let fixed = TinyText3d::new("iii", Color::WHITE).with_scale(Vec3::ONE).with_spacing(Vec3::X * 6.0);
+    let proportional = fixed.clone().with_advance(TextAdvance::Proportional);
+    assert!(proportional.width(&font) < fixed.width(&font));
+    let right = proportional.with_align(TextAlign::Right, VerticalAlign::Baseline);
+    assert_eq!(right.origin(&font).x, -right.width(&font) + 0.5);
+}
+
+fn unit_text(text: &str) -> TinyText3d {
+    TinyText3d::new(text, Color::WHITE).with_scale(Vec3::ONE).with_spacing(Vec3::X * 6.0)
+}
+
+#[test]
+fn measure_covers_every_line_of_the_text() {
+    let font = TinyFont::new();
+    // One 11-cube line: bounds run along the cube edges around the origin
+    let bounds = unit_text("AB").measure(&font);
+    assert_eq!(bounds.min, Vec2::new(-0.5, -0.5));
+    assert_eq!(bounds.max, Vec2::new(10.5, 6.5));
+
+    // Lines stack downwards, 7 rows plus the line spacing apart
+    let text = unit_text("AB\nABC").with_line_spacing(2.0).with_align(TextAlign::Center, VerticalAlign::Middle);
+    let layout = text.layout(&font);
+    assert_eq!(layout.lines.iter().map(|line| line.text.as_str()).collect::<Vec<_>>(), ["AB", "ABC"]);
+    assert_eq!(layout.lines[0].origin.y - layout.lines[1].origin.y, 9.0);
+    assert_eq!(layout.bounds.size(), Vec2::new(17.0, 16.0));
+    assert_eq!(layout.bounds.center(), Vec2::ZERO);
+    // Each line is centered on its own
+    assert_eq!(layout.lines[0].origin.x, -5.0);
+    assert_eq!(layout.lines[1].origin.x, -8.0);
+
+    let right = unit_text("AB\nABC").with_align(TextAlign::Right, VerticalAlign::Top).layout(&font);
+    assert!(right.lines.iter().all(|line| line.origin.x + line.width - 0.5 == 0.0));
+    assert_eq!(right.bounds.max.y, 0.0);
+}
+
+#[test]
+fn wrapping_breaks_lines_between_words() {
+    let font = TinyFont::new();
+    // "AB CD" is 29 cubes wide, each word 11
+    let layout = unit_text("AB  CD\n\nEF").with_wrap_width(20.0).layout(&font);
+    let lines: Vec<_> = layout.lines.iter().map(|line| line.text.as_str()).collect();
+    assert_eq!(lines, ["AB", "CD", "", "EF"]);
+    assert!(layout.bounds.size().x <= 20.0);
+
+    let wide = unit_text("AB CD").with_wrap_width(29.0).layout(&font);
+    assert_eq!(wide.lines.len(), 1);
+    // A word wider than the wrap width keeps a line of its own
+    let long = unit_text("ABCDEF G").with_wrap_width(20.0).layout(&font);
+    assert_eq!(long.lines.iter().map(|line| line.text.as_str()).collect::<Vec<_>>(), ["ABCDEF", "G"]);
+}
+
+#[test]
+fn fit_shrinks_the_text_into_its_box() {
+    let font = TinyFont::new();
+    let text = unit_text("ABCD").with_fit(Vec2::new(11.5, 100.0));
+    let layout = text.layout(&font);
+    assert_eq!(layout.scale, Vec3::splat(0.5));
+    assert_eq!(layout.spacing, Vec3::X * 3.0);
+    assert!((layout.bounds.size().x - 11.5).abs() < 1e-4);
+    // Text that already fits is never enlarged
+    assert_eq!(unit_text("A").with_fit(Vec2::splat(100.0)).layout(&font).scale, Vec3::ONE);
+
+    // Wrapped text is rewrapped at the size it is shrunk to, so it ends up
+    // larger than the same text squeezed onto one line
+    let long = "AVERAGE ANNUAL RAINFALL";
+    let single = unit_text(long).with_fit(Vec2::new(60.0, 40.0)).layout(&font);
+    let wrapped = unit_text(long).with_wrap_width(60.0).with_fit(Vec2::new(60.0, 40.0)).layout(&font);
+    assert_eq!(single.lines.len(), 1);
+    assert!(wrapped.lines.len() > 1);
+    assert!(wrapped.scale.x > single.scale.x);
+    let size = wrapped.bounds.size();
+    assert!(size.x <= 60.0 + 1e-3 && size.y <= 40.0 + 1e-3);
+}
+
+#[test]
+fn newlines_render_as_separate_lines() {
+    let mut app = app();
+    let text = app
+        .world
+        .spawn(TinyText3dBundle::new(TinyText3d::new("Hi\nyo", Color::WHITE), Transform::default()))
+        .id();
+    app.update();
+    assert_eq!(children_of(&app, text), cube_count("Hiyo"));
+    let mut heights: Vec<i32> = app
+        .world
+        .get::<Children>(text)
+        .unwrap()
+        .iter()
+        .map(|&child| (app.world.get::<Transform>(child).unwrap().translation.y * 10.0).round() as i32)
+        .collect();
+    heights.sort();
+    heights.dedup();
+    // 'y' descends two rows below the second line's baseline, two lines 10 rows apart
+    assert_eq!(heights.first(), Some(&((-10 - 2) * 2)));
+}
+
+/// Faces a glyph shows once shared faces between neighboring cubes are culled
+fn exposed_faces(c: char) -> usize {
+    let font = TinyFont::new();
+    let cells: HashSet<(i32, i32)> = font.get_glyph(c).unwrap().iter().map(|cube| (cube.x, cube.y)).collect();
+    cells
+        .iter()
+        .map(|&(x, y)| {
+            let hidden = [(1, 0), (-1, 0), (0, 1), (0, -1)]
+                .iter()
+                .filter(|(dx, dy)| cells.contains(&(x + dx, y + dy)))
+                .count();
+            6 - hidden
+        })
+        .sum()
+}
+
+fn positions(mesh: &Mesh) -> Vec<[f32; 3]> {
+    match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(positions)) => positions.clone(),
+        _ => panic!("missing positions"),
+    }
+}
+
+#[test]
+fn merged_mesh_culls_faces_between_neighboring_cubes() {
+    let mesh = TinyText::build_mesh(&TinyFont::new(), "H1", Vec3::ZERO, Vec3::ONE, Vec3::X * 6.0, TextAdvance::Fixed);
+    let faces = exposed_faces('H') + exposed_faces('1');
+    assert!(faces < 6 * cube_count("H1"));
+    assert_eq!(positions(&mesh).len(), faces * 4);
+    assert_eq!(mesh.indices().unwrap().len(), faces * 6);
+
+    // Every vertex lies on the surface of one of the glyph's cubes
+    let font = TinyFont::new();
+    let centers: Vec<Vec3> = ['H', '1']
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &c)| {
+            let cursor = Vec3::X * 6.0 * i as f32;
+            font.get_glyph(c).unwrap().iter().map(move |cube| cursor + Vec3::new(cube.x as f32, cube.y as f32, 0.0))
+        })
+        .collect();
+    for position in positions(&mesh) {
+        let position = Vec3::from(position);
+        assert!(centers.iter().any(|center| (position - *center).abs().max_element() == 0.5));
+    }
+}
+
+#[test]
+fn merged_mode_spawns_a_single_child() {
+    let mut app = app();
+    let text = app
+        .world
+        .spawn(TinyText3dBundle::new(
+            TinyText3d::new("Merged", Color::WHITE).with_mode(TextMeshMode::Merged),
+            Transform::default(),
+        ))
+        .id();
+    app.update();
+    assert_eq!(children_of(&app, text), 1);
+
+    app.world.get_mut::<TinyText3d>(text).unwrap().text = " ".to_string();
+    app.update();
+    assert_eq!(children_of(&app, text), 0);
+}
+
+#[test]
+fn rebuilding_reuses_the_cube_mesh_and_color_materials() {
+    let mut app = app();
+    let white = Color::WHITE;
+    let texts: Vec<Entity> = ["A", "B"]
+        .into_iter()
+        .map(|text| app.world.spawn(TinyText3dBundle::new(TinyText3d::new(text, white), Transform::default())).id())
+        .collect();
+    app.update();
+    assert_eq!(app.world.resource::<Assets<Mesh>>().len(), 1);
+    assert_eq!(app.world.resource::<Assets<StandardMaterial>>().len(), 1);
+
+    for (i, &text) in texts.iter().enumerate() {
+        app.world.get_mut::<TinyText3d>(text).unwrap().text = format!("{}", i + 7);
+        app.update();
+    }
+    app.world.get_mut::<TinyText3d>(texts[0]).unwrap().color = Color::RED;
+    app.update();
+    assert_eq!(app.world.resource::<Assets<Mesh>>().len(), 1);
+    assert_eq!(app.world.resource::<Assets<StandardMaterial>>().len(), 2);
+    assert_eq!(app.world.resource::<TinyTextAssets>().material_count(), 2);
+}
+
+#[test]
+fn materials_no_text_uses_are_released() {
+    let mut app = app();
+    let text = app
+        .world
+        .spawn(TinyText3dBundle::new(TinyText3d::new("1", Color::WHITE), Transform::default()))
+        .id();
+    app.update();
+    for color in [Color::RED, Color::GREEN, Color::BLUE, Color::RED] {
+        app.world.get_mut::<TinyText3d>(text).unwrap().color = color;
+        app.update();
+    }
+    // The color in use and the one just replaced, whose cubes were still
+    // alive when the new one was added
+    assert_eq!(app.world.resource::<TinyTextAssets>().material_count(), 2);
+}
+
+#[test]
+fn spawner_places_loose_cubes_with_the_shared_assets() {
+    fn spawn_label(mut text: TinyTextSpawner) {
+        text.spawn_simple("Hi", Vec3::ZERO, Color::WHITE);
+        text.spawn_simple("Hi", Vec3::Y * 2.0, Color::WHITE);
+    }
+    let mut app = app();
+    app.add_systems(Update, spawn_label);
+    app.update();
+    assert_eq!(app.world.entities().len() as usize, 2 * cube_count("Hi"));
+    assert_eq!(app.world.resource::<Assets<Mesh>>().len(), 1);
+    assert_eq!(app.world.resource::<Assets<StandardMaterial>>().len(), 1);
+}
+
+#[test]
+fn spawner_lays_out_proportional_text() {
+    fn spawn_labels(mut text: TinyTextSpawner) {
+        let (scale, offset) = (Vec3::ONE, Vec3::new(6.0, 0.0, 0.0));
+        text.spawn_text("il", Vec3::ZERO, scale, Quat::IDENTITY, offset, Color::WHITE);
+        let origin = Vec3::Y * 20.0;
+        text.spawn_text_with("il", origin, scale, Quat::IDENTITY, offset, Color::WHITE, TextAdvance::Proportional);
+    }
+    let mut app = app();
+    app.add_systems(Update, spawn_labels);
+    app.update();
+
+    // Width from the left edge of the leftmost cube to the right edge of the
+    // rightmost, for the fixed text below y = 20 or the proportional above
+    let mut cubes = app.world.query::<&Transform>();
+    let xs: Vec<(f32, bool)> = cubes.iter(&app.world).map(|t| (t.translation.x, t.translation.y >= 20.0)).collect();
+    let width = |proportional: bool| {
+        let xs = xs.iter().filter(|(_, above)| *above == proportional).map(|(x, _)| *x);
+        xs.clone().fold(f32::NEG_INFINITY, f32::max) - xs.fold(f32::INFINITY, f32::min) + 1.0
+    };
+    let font = TinyFont::new();
+    assert_eq!(width(true), TinyText::measure_width(&font, "il", Vec3::ONE, Vec3::ZERO, TextAdvance::Proportional));
+    assert!(width(false) > width(true) + 2.0);
+}
+
+fn normals(mesh: &Mesh) -> Vec<[f32; 3]> {
+    match mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+        Some(VertexAttributeValues::Float32x3(normals)) => normals.clone(),
+        _ => panic!("missing normals"),
+    }
+}
+
+#[test]
+fn extruded_glyphs_show_only_their_outer_faces() {
+    let font = TinyFont::new();
+    let flat = TextStyle::default();
+    let deep = TextStyle::default().with_depth(3);
+    let glyph = font.get_glyph('H').unwrap();
+    assert_eq!(deep.glyph_cells(glyph).len(), 3 * glyph.len());
+    assert!(deep.glyph_cells(glyph).iter().all(|cell| (-2..=0).contains(&cell.z)));
+
+    // Three layers keep the flat glyph's front and back and triple its sides
+    let pens = [('H', Vec3::ZERO)];
+    let faces = |style: TextStyle| {
+        let mesh = build_voxel_mesh(&style.glyph_voxels(&font, &pens), Vec3::ONE, VoxelShape::Cube);
+        positions(&mesh).len() / 4
+    };
+    let sides = exposed_faces('H') - 2 * glyph.len();
+    assert_eq!(faces(flat), exposed_faces('H'));
+    assert_eq!(faces(deep), 2 * glyph.len() + 3 * sides);
+}
+
+#[test]
+fn shadow_sits_behind_the_glyph_and_outlines_grow_it() {
+    let font = TinyFont::new();
+    let glyph = font.get_glyph('I').unwrap();
+    let pens = [('I', Vec3::ZERO)];
+    let style = TextStyle::default().with_depth(2).with_shadow(TextShadow::drop(Color::BLACK));
+    let shadow = style.shadow_voxels(&font, &pens, Vec3::splat(0.5));
+    assert_eq!(shadow.len(), 1);
+    assert_eq!(shadow[0].0, Vec3::new(0.5, -0.5, -1.0));
+    assert_eq!(shadow[0].1.len(), glyph.len());
+
+    let outlined = TextStyle::default().with_shadow(TextShadow::outline(Color::BLACK)).shadow_cells(glyph);
+    assert!(glyph.iter().all(|cube| outlined.contains(&IVec3::new(cube.x, cube.y, 0))));
+    let (min_x, max_x) = (glyph.iter().map(|cube| cube.x).min().unwrap(), glyph.iter().map(|cube| cube.x).max().unwrap());
+    assert_eq!(outlined.iter().map(|cell| cell.x).min(), Some(min_x - 1));
+    assert_eq!(outlined.iter().map(|cell| cell.x).max(), Some(max_x + 1));
+    assert!(TextStyle::default().shadow_voxels(&font, &pens, Vec3::ONE).is_empty());
+}
+
+#[test]
+fn rounded_voxel_shapes_face_outwards() {
+    for shape in [VoxelShape::Sphere, VoxelShape::Beveled] {
+        let mesh = shape.mesh();
+        let (positions, normals) = (positions(&mesh), normals(&mesh));
+        let indices: Vec<usize> = mesh.indices().unwrap().iter().collect();
+        assert!(positions.iter().all(|p| Vec3::from(*p).abs().max_element() <= 0.5 + 1e-5));
+        for triangle in indices.chunks(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| Vec3::from(positions[triangle[i]]));
+            let winding = (b - a).cross(c - a);
+            let center = (a + b + c) / 3.0;
+            assert!(winding.dot(center) >= -1e-6, "{:?} triangle winds inwards", shape);
+            assert!(Vec3::from(normals[triangle[0]]).dot(center) > 0.0);
+        }
+    }
+    // A beveled cube is 6 faces, 12 edge strips and 8 corners
+    let beveled = VoxelShape::Beveled.mesh();
+    assert_eq!(beveled.indices().unwrap().len(), (18 * 2 + 8) * 3);
+}
+
+#[test]
+fn styled_text_spawns_a_second_color_layer() {
+    let mut app = app();
+    let style = TextStyle::default()
+        .with_depth(2)
+        .with_voxel(VoxelShape::Sphere)
+        .with_shadow(TextShadow::outline(Color::BLACK));
+    let text = app
+        .world
+        .spawn(TinyText3dBundle::new(
+            TinyText3d::new("I", Color::WHITE).with_style(style).with_mode(TextMeshMode::Merged),
+            Transform::default(),
+        ))
+        .id();
+    app.update();
+    assert_eq!(children_of(&app, text), 2);
+    assert_eq!(app.world.resource::<TinyTextAssets>().material_count(), 2);
+
+    let glyph = TinyFont::new().get_glyph('I').unwrap().clone();
+    app.world.get_mut::<TinyText3d>(text).unwrap().mode = TextMeshMode::Cubes;
+    app.update();
+    let cubes = 2 * glyph.len() + style.shadow_cells(&glyph).len();
+    assert_eq!(children_of(&app, text), cubes);
+    // Spheres replace the cube mesh
+    let mesh = app.world.get::<Handle<Mesh>>(app.world.get::<Children>(text).unwrap()[0]).unwrap();
+    let sphere_vertices = VoxelShape::Sphere.mesh().count_vertices();
+    assert_eq!(app.world.resource::<Assets<Mesh>>().get(mesh).unwrap().count_vertices(), sphere_vertices);
+}
+
+#[test]
+fn spans_color_their_characters_even_after_wrapping() {
+    let text = TinyText3d::from_spans([("AB ", Color::RED), ("CD", Color::BLUE)]);
+    assert_eq!(text.text, "AB CD");
+    assert_eq!(span_colors(&text.text, &text.spans), [Some(Color::RED), Some(Color::RED), Some(Color::BLUE), Some(Color::BLUE)]);
+    // Later spans win; uncovered characters use the fill
+    let spans = span_colors("AB C", &text.clone().with_span(1, 2, Color::GREEN).spans);
+    assert_eq!(spans, [Some(Color::RED), Some(Color::GREEN), Some(Color::BLUE)]);
+
+    let font = TinyFont::new();
+    let wrapped = text.with_scale(Vec3::ONE).with_spacing(Vec3::X * 6.0).with_wrap_width(12.0);
+    let layout = wrapped.layout(&font);
+    assert_eq!(layout.lines.len(), 2);
+    let spans = span_colors(&wrapped.text, &wrapped.spans);
+    let painter = Painter { color: Color::WHITE, fill: TextFill::Solid, spans: &spans, bounds: layout.bounds };
+    let groups = painter.color_groups(&font, &layout.pen_positions(&font, TextAdvance::Fixed), &TextStyle::default(), Vec3::ONE);
+    assert_eq!(groups.iter().map(|(color, _)| *color).collect::<Vec<_>>(), [Color::RED, Color::BLUE]);
+    // "CD" moved to the second line keeps its color
+    assert!(groups[1].1.iter().all(|(pen, _)| pen.y < 0.0));
+}
+
+#[test]
+fn gradients_run_across_the_text_bounds() {
+    let (from, to) = (Color::rgb(1.0, 0.0, 0.0), Color::rgb(0.0, 0.0, 1.0));
+    assert_eq!(gradient(from, to, 0.0), from);
+    assert_eq!(gradient(from, to, 1.5), to);
+
+    let font = TinyFont::new();
+    for fill in [TextFill::Horizontal(from, to), TextFill::Vertical(from, to)] {
+        let text = TinyText3d::new("HELLO\nWORLD", Color::WHITE).with_scale(Vec3::ONE).with_spacing(Vec3::X * 6.0);
+        let layout = text.layout(&font);
+        let pens = layout.pen_positions(&font, TextAdvance::Fixed);
+        let painter = Painter { color: Color::WHITE, fill, spans: &[], bounds: layout.bounds };
+        let groups = painter.color_groups(&font, &pens, &TextStyle::default(), Vec3::ONE);
+        assert!(groups.len() > 2 && groups.len() <= GRADIENT_STEPS as usize);
+        // The first color sits at the left or top edge
+        let first_of = |color: Color| {
+            let (_, voxels) = groups.iter().find(|(c, _)| *c == color).unwrap();
+            voxels.iter().flat_map(|(pen, cells)| cells.iter().map(move |cell| *pen + cell.as_vec3())).next().unwrap()
+        };
+        match fill {
+            TextFill::Horizontal(..) => assert!(first_of(from).x < first_of(to).x),
+            _ => assert!(first_of(from).y > first_of(to).y),
+        }
+    }
+}
+
+#[test]
+fn led_text_glows_over_unlit_off_pixels() {
+    let font = TinyFont::new();
+    let glyph = font.get_glyph('I').unwrap();
+    let off = off_pixel_voxels(&font, &[('I', Vec3::ZERO)], TextAdvance::Fixed);
+    assert_eq!(off[0].1.len(), 35 - glyph.len());
+    // Proportional off pixels only cover the glyph's own columns
+    let narrow = off_pixel_voxels(&font, &[('I', Vec3::ZERO)], TextAdvance::Proportional);
+    assert_eq!(narrow[0].1.len(), (font.metrics('I').width * 7) as usize - glyph.len());
+
+    let mut app = app();
+    let led = TextMaterial::led(2.0).with_off_pixels(Color::DARK_GRAY);
+    let text = app
+        .world
+        .spawn(TinyText3dBundle::new(TinyText3d::new("I", Color::RED).with_material(led), Transform::default()))
+        .id();
+    app.update();
+    assert_eq!(children_of(&app, text), 35);
+    let materials = app.world.resource::<Assets<StandardMaterial>>();
+    assert_eq!(materials.len(), 2);
+    assert!(materials.iter().any(|(_, material)| material.emissive == Color::RED * 2.0 && !material.unlit));
+    assert!(materials.iter().any(|(_, material)| material.base_color == Color::DARK_GRAY && material.unlit));
+}
@@ -0,0 +1,43 @@
+use bevy::prelude::Color;
+use pi_vs_pi::pi_vs_pi::radius::RadiusConfig;
+use pi_vs_pi::pi_vs_pi::tooltip::tooltip_text;
+use pi_vs_pi::pi_vs_pi::{CompetitionData, Dataset, EntityDef, GameData};
+
+fn game_data() -> GameData {
+    GameData::new(Dataset {
+        entities: vec![
+            EntityDef::new("Kobolds", Color::RED),
+            EntityDef::new("Troglodytes", Color::BLUE),
+            EntityDef::new("Goblins", Color::GREEN),
+        ],
+        categories: vec![
+            CompetitionData::new("Strength", vec![2.0, 6.0, 2.0]),
+            CompetitionData::new("Cunning", vec![6.0, 0.0, 0.0]),
+        ],
+        radius: RadiusConfig::default(),
+    })
+}
+
+#[test]
+fn tooltip_lists_every_value_and_both_shares() {
+    let text = tooltip_text(&game_data(), 0, 1);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            "Strength",
+            "  Kobolds: 2",
+            "▶ Troglodytes: 6",
+            "  Goblins: 2",
+            "Angle: 100.0% of the Troglodytes total",
+            "Radius: 60.0% of the category total",
+        ]
+    );
+}
+
+#[test]
+fn tooltip_shares_match_the_slice_geometry() {
+    let text = tooltip_text(&game_data(), 0, 0);
+    assert!(text.contains("Angle: 25.0% of the Kobolds total"), "{}", text);
+    assert!(text.contains("Radius: 20.0% of the category total"), "{}", text);
+}